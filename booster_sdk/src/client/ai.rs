@@ -8,7 +8,7 @@ use crate::dds::{
     AI_API_TOPIC, DdsNode, DdsSubscription, LUI_API_TOPIC, RpcClient, RpcClientOptions,
     ai_subtitle_topic, lui_asr_chunk_topic,
 };
-use crate::types::Result;
+use crate::types::{BoosterError, Result};
 
 crate::api_id_enum! {
     /// AI chat RPC API identifiers.
@@ -34,6 +34,7 @@ crate::api_id_enum! {
 
 /// TTS configuration for AI chat.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TtsConfig {
     pub voice_type: String,
     pub ignore_bracket_text: Vec<i8>,
@@ -41,6 +42,7 @@ pub struct TtsConfig {
 
 /// LLM prompt configuration for AI chat.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct LlmConfig {
     pub system_prompt: String,
     pub welcome_msg: String,
@@ -49,13 +51,87 @@ pub struct LlmConfig {
 
 /// ASR interruption configuration.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct AsrConfig {
     pub interrupt_speech_duration: i32,
     pub interrupt_keywords: Vec<String>,
 }
 
+impl AsrConfig {
+    /// Start a validating builder; see [`AsrConfigBuilder::build`].
+    pub fn builder() -> AsrConfigBuilder {
+        AsrConfigBuilder::default()
+    }
+}
+
+/// Builder for [`AsrConfig`] that normalizes and validates its inputs.
+#[derive(Debug, Clone, Default)]
+pub struct AsrConfigBuilder {
+    interrupt_speech_duration: i32,
+    interrupt_keywords: Vec<String>,
+}
+
+impl AsrConfigBuilder {
+    /// How long the user must speak before interrupting the robot.
+    #[must_use]
+    pub fn interrupt_speech_duration(mut self, duration: i32) -> Self {
+        self.interrupt_speech_duration = duration;
+        self
+    }
+
+    /// Add one interrupt keyword.
+    #[must_use]
+    pub fn keyword(mut self, keyword: impl Into<String>) -> Self {
+        self.interrupt_keywords.push(keyword.into());
+        self
+    }
+
+    /// Add several interrupt keywords.
+    #[must_use]
+    pub fn keywords<I, S>(mut self, keywords: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.interrupt_keywords
+            .extend(keywords.into_iter().map(Into::into));
+        self
+    }
+
+    /// Trim and deduplicate keywords, keeping first-seen order.
+    ///
+    /// Fails on a blank keyword or a negative speech duration.
+    pub fn build(self) -> Result<AsrConfig> {
+        if self.interrupt_speech_duration < 0 {
+            return Err(BoosterError::Validation(format!(
+                "interrupt_speech_duration must be non-negative, got {}",
+                self.interrupt_speech_duration
+            )));
+        }
+
+        let mut interrupt_keywords: Vec<String> = Vec::with_capacity(self.interrupt_keywords.len());
+        for keyword in &self.interrupt_keywords {
+            let keyword = keyword.trim();
+            if keyword.is_empty() {
+                return Err(BoosterError::Validation(
+                    "interrupt keywords must not be blank".to_owned(),
+                ));
+            }
+            if !interrupt_keywords.iter().any(|seen| seen == keyword) {
+                interrupt_keywords.push(keyword.to_owned());
+            }
+        }
+
+        Ok(AsrConfig {
+            interrupt_speech_duration: self.interrupt_speech_duration,
+            interrupt_keywords,
+        })
+    }
+}
+
 /// Parameters for starting AI chat.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct StartAiChatParameter {
     pub interrupt_mode: bool,
     pub asr_config: AsrConfig,
@@ -66,24 +142,28 @@ pub struct StartAiChatParameter {
 
 /// Parameters for AI speech output.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SpeakParameter {
     pub msg: String,
 }
 
 /// LUI TTS startup configuration.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct LuiTtsConfig {
     pub voice_type: String,
 }
 
 /// Parameters for sending TTS text to LUI.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct LuiTtsParameter {
     pub text: String,
 }
 
 /// AI subtitle topic payload.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Subtitle {
     pub magic_number: String,
     pub text: String,
@@ -97,6 +177,7 @@ pub struct Subtitle {
 
 /// LUI ASR chunk topic payload.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct AsrChunk {
     pub text: String,
 }
@@ -133,6 +214,11 @@ impl AiClient {
         self.rpc.node()
     }
 
+    /// Access the underlying RPC client, e.g. to call API ids not wrapped here.
+    pub fn rpc(&self) -> &RpcClient {
+        &self.rpc
+    }
+
     /// Start AI chat with the provided configuration.
     pub async fn start_ai_chat(&self, param: &StartAiChatParameter) -> Result<()> {
         self.rpc.call_serialized(AiApiId::StartAiChat, param).await
@@ -140,7 +226,7 @@ impl AiClient {
 
     /// Stop the active AI chat session.
     pub async fn stop_ai_chat(&self) -> Result<()> {
-        self.rpc.call_void(AiApiId::StopAiChat, "").await
+        self.rpc.call_serialized(AiApiId::StopAiChat, &()).await
     }
 
     /// Request the AI service to speak a message.
@@ -150,18 +236,49 @@ impl AiClient {
 
     /// Enable face tracking in the AI service.
     pub async fn start_face_tracking(&self) -> Result<()> {
-        self.rpc.call_void(AiApiId::StartFaceTracking, "").await
+        self.rpc
+            .call_serialized(AiApiId::StartFaceTracking, &())
+            .await
     }
 
     /// Disable face tracking in the AI service.
     pub async fn stop_face_tracking(&self) -> Result<()> {
-        self.rpc.call_void(AiApiId::StopFaceTracking, "").await
+        self.rpc
+            .call_serialized(AiApiId::StopFaceTracking, &())
+            .await
     }
 
     /// Subscribe to AI subtitle messages.
     pub fn subscribe_subtitle(&self) -> Result<DdsSubscription<Subtitle>> {
         self.rpc.node().subscribe(&ai_subtitle_topic(), 16)
     }
+
+    /// Wait for a final user subtitle matching `predicate`.
+    ///
+    /// Partial (non-definite) subtitles and the robot's own speech are
+    /// skipped. Only subtitles received after this call are considered.
+    ///
+    /// Each call opens its own subscription, which is closed again when the
+    /// wait finishes. To watch for many utterances, keep one
+    /// [`Self::subscribe_subtitle`] subscription and use
+    /// [`DdsSubscription::wait_for`] on it instead.
+    pub async fn wait_for_user_utterance(
+        &self,
+        predicate: impl Fn(&Subtitle) -> bool,
+        timeout: Duration,
+    ) -> Result<Subtitle> {
+        let mut subtitles = self.subscribe_subtitle()?;
+        subtitles
+            .wait_for(
+                |subtitle| {
+                    subtitle.definite
+                        && subtitle.user_id != BOOSTER_ROBOT_USER_ID
+                        && predicate(subtitle)
+                },
+                timeout,
+            )
+            .await
+    }
 }
 
 /// High-level RPC client for LUI ASR/TTS features.
@@ -193,14 +310,19 @@ impl LuiClient {
         self.rpc.node()
     }
 
+    /// Access the underlying RPC client, e.g. to call API ids not wrapped here.
+    pub fn rpc(&self) -> &RpcClient {
+        &self.rpc
+    }
+
     /// Start ASR.
     pub async fn start_asr(&self) -> Result<()> {
-        self.rpc.call_void(LuiApiId::StartAsr, "").await
+        self.rpc.call_serialized(LuiApiId::StartAsr, &()).await
     }
 
     /// Stop ASR.
     pub async fn stop_asr(&self) -> Result<()> {
-        self.rpc.call_void(LuiApiId::StopAsr, "").await
+        self.rpc.call_serialized(LuiApiId::StopAsr, &()).await
     }
 
     /// Start TTS with the given configuration.
@@ -210,7 +332,7 @@ impl LuiClient {
 
     /// Stop TTS.
     pub async fn stop_tts(&self) -> Result<()> {
-        self.rpc.call_void(LuiApiId::StopTts, "").await
+        self.rpc.call_serialized(LuiApiId::StopTts, &()).await
     }
 
     /// Send text to TTS.
@@ -223,3 +345,96 @@ impl LuiClient {
         self.rpc.node().subscribe(&lui_asr_chunk_topic(), 16)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn asr_config_builder_normalizes_keywords() {
+        let config = AsrConfig::builder()
+            .interrupt_speech_duration(300)
+            .keywords([" stop ", "wait", "stop"])
+            .keyword("wait")
+            .build()
+            .unwrap();
+        assert_eq!(config.interrupt_speech_duration, 300);
+        assert_eq!(config.interrupt_keywords, ["stop", "wait"]);
+    }
+
+    #[test]
+    fn asr_config_builder_rejects_invalid_input() {
+        assert!(matches!(
+            AsrConfig::builder().keyword("  ").build(),
+            Err(BoosterError::Validation(_))
+        ));
+        assert!(matches!(
+            AsrConfig::builder().interrupt_speech_duration(-1).build(),
+            Err(BoosterError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn start_ai_chat_parameter_wire_format() {
+        let param = StartAiChatParameter {
+            interrupt_mode: true,
+            asr_config: AsrConfig {
+                interrupt_speech_duration: 200,
+                interrupt_keywords: vec!["stop".to_owned()],
+            },
+            llm_config: LlmConfig {
+                system_prompt: "be brief".to_owned(),
+                welcome_msg: "hi".to_owned(),
+                prompt_name: "default".to_owned(),
+            },
+            tts_config: TtsConfig {
+                voice_type: "female".to_owned(),
+                ignore_bracket_text: vec![1, 2],
+            },
+            enable_face_tracking: false,
+        };
+        assert_eq!(
+            serde_json::to_value(param).unwrap(),
+            json!({
+                "interrupt_mode": true,
+                "asr_config": {
+                    "interrupt_speech_duration": 200,
+                    "interrupt_keywords": ["stop"],
+                },
+                "llm_config": {
+                    "system_prompt": "be brief",
+                    "welcome_msg": "hi",
+                    "prompt_name": "default",
+                },
+                "tts_config": { "voice_type": "female", "ignore_bracket_text": [1, 2] },
+                "enable_face_tracking": false,
+            })
+        );
+    }
+
+    #[test]
+    fn text_parameters_wire_format() {
+        assert_eq!(
+            serde_json::to_value(SpeakParameter {
+                msg: "hello".to_owned()
+            })
+            .unwrap(),
+            json!({ "msg": "hello" })
+        );
+        assert_eq!(
+            serde_json::to_value(LuiTtsParameter {
+                text: "hello".to_owned()
+            })
+            .unwrap(),
+            json!({ "text": "hello" })
+        );
+        assert_eq!(
+            serde_json::to_value(LuiTtsConfig {
+                voice_type: "male".to_owned()
+            })
+            .unwrap(),
+            json!({ "voice_type": "male" })
+        );
+    }
+}
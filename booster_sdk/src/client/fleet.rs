@@ -0,0 +1,102 @@
+//! Management of locomotion clients for several robots.
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::Arc;
+
+use futures::future::join_all;
+
+use crate::dds::RpcClientOptions;
+use crate::types::Result;
+
+use super::BoosterClient;
+
+/// Set of locomotion clients keyed by a caller-chosen robot id.
+///
+/// Each robot is usually reached on its own DDS domain, so every entry owns a
+/// separate client and participant built from its own [`RpcClientOptions`].
+#[derive(Default)]
+pub struct RobotFleet {
+    robots: BTreeMap<String, Arc<BoosterClient>>,
+}
+
+impl RobotFleet {
+    /// Create an empty fleet.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Connect to a robot with `options` and add it under `id`.
+    ///
+    /// Replaces any robot previously registered under the same id.
+    pub fn connect(&mut self, id: impl Into<String>, options: RpcClientOptions) -> Result<()> {
+        let client = BoosterClient::with_options(options)?;
+        self.insert(id, client);
+        Ok(())
+    }
+
+    /// Add an existing client under `id`, returning the one it replaces.
+    pub fn insert(
+        &mut self,
+        id: impl Into<String>,
+        client: BoosterClient,
+    ) -> Option<Arc<BoosterClient>> {
+        self.robots.insert(id.into(), Arc::new(client))
+    }
+
+    /// Remove the robot registered under `id`.
+    pub fn remove(&mut self, id: &str) -> Option<Arc<BoosterClient>> {
+        self.robots.remove(id)
+    }
+
+    /// Client for the robot registered under `id`.
+    #[must_use]
+    pub fn get(&self, id: &str) -> Option<&Arc<BoosterClient>> {
+        self.robots.get(id)
+    }
+
+    /// Registered robot ids in sorted order.
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.robots.keys().map(String::as_str)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.robots.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.robots.is_empty()
+    }
+
+    /// Run `f` against every robot concurrently and collect each outcome by id.
+    ///
+    /// A failure on one robot does not cancel the others.
+    ///
+    /// ```no_run
+    /// # use booster_sdk::client::fleet::RobotFleet;
+    /// # async fn demo(fleet: &RobotFleet) {
+    /// let results = fleet
+    ///     .broadcast(|client| async move { client.move_robot(0.0, 0.0, 0.0).await })
+    ///     .await;
+    /// for (id, result) in results {
+    ///     if let Err(err) = result {
+    ///         eprintln!("{id}: {err}");
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn broadcast<F, Fut, T>(&self, f: F) -> BTreeMap<String, Result<T>>
+    where
+        F: Fn(Arc<BoosterClient>) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let calls = self.robots.iter().map(|(id, client)| {
+            let call = f(Arc::clone(client));
+            async move { (id.clone(), call.await) }
+        });
+        join_all(calls).await.into_iter().collect()
+    }
+}
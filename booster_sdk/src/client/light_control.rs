@@ -1,11 +1,12 @@
 //! LED light control RPC client.
 
+use std::sync::{Mutex, PoisonError};
 use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 
 use crate::dds::{LIGHT_CONTROL_API_TOPIC, RpcClient, RpcClientOptions};
-use crate::types::Result;
+use crate::types::{NamedColor, Result};
 
 crate::api_id_enum! {
     /// LED light control RPC API identifiers.
@@ -17,6 +18,7 @@ crate::api_id_enum! {
 
 /// RGB color payload for LED control.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SetLedLightColorParameter {
     pub r: u8,
     pub g: u8,
@@ -38,11 +40,19 @@ impl SetLedLightColorParameter {
 
         Some(Self { r, g, b })
     }
+
+    /// Look up a color by name, ignoring case; see [`NamedColor::from_name`].
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let (r, g, b) = NamedColor::from_name(name)?.rgb()?;
+        Some(Self { r, g, b })
+    }
 }
 
 /// High-level RPC client for LED light control APIs.
 pub struct LightControlClient {
     rpc: RpcClient,
+    last_color: Mutex<Option<SetLedLightColorParameter>>,
 }
 
 impl LightControlClient {
@@ -61,7 +71,15 @@ impl LightControlClient {
     /// Create a light control client with custom RPC options.
     pub fn with_options(options: RpcClientOptions) -> Result<Self> {
         let rpc = RpcClient::for_topic(options, LIGHT_CONTROL_API_TOPIC)?;
-        Ok(Self { rpc })
+        Ok(Self {
+            rpc,
+            last_color: Mutex::new(None),
+        })
+    }
+
+    /// Access the underlying RPC client, e.g. to call API ids not wrapped here.
+    pub fn rpc(&self) -> &RpcClient {
+        &self.rpc
     }
 
     /// Set LED light color from RGB values.
@@ -74,13 +92,73 @@ impl LightControlClient {
     pub async fn set_led_light_color_param(&self, param: &SetLedLightColorParameter) -> Result<()> {
         self.rpc
             .call_serialized(LightApiId::SetLedLightColor, param)
-            .await
+            .await?;
+        self.set_last_color(Some(*param));
+        Ok(())
     }
 
     /// Stop LED light control.
     pub async fn stop_led_light_control(&self) -> Result<()> {
         self.rpc
-            .call_void(LightApiId::StopLedLightControl, "")
-            .await
+            .call_serialized(LightApiId::StopLedLightControl, &())
+            .await?;
+        self.set_last_color(None);
+        Ok(())
+    }
+
+    /// Last color successfully set through this client.
+    ///
+    /// The light service has no API to read the LED state back, so this is a
+    /// client-side record: `None` before the first set or after
+    /// [`Self::stop_led_light_control`], and unaware of changes made by other
+    /// clients.
+    pub fn last_color(&self) -> Option<SetLedLightColorParameter> {
+        *self
+            .last_color
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn set_last_color(&self, color: Option<SetLedLightColorParameter>) {
+        *self
+            .last_color
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = color;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn named_colors_are_case_insensitive() {
+        assert_eq!(
+            SetLedLightColorParameter::from_name(" Amber "),
+            Some(SetLedLightColorParameter {
+                r: 255,
+                g: 191,
+                b: 0
+            })
+        );
+        assert_eq!(
+            SetLedLightColorParameter::from_name("off"),
+            SetLedLightColorParameter::from_hex("#000000")
+        );
+        assert_eq!(SetLedLightColorParameter::from_name("chartreuse-ish"), None);
+    }
+
+    #[test]
+    fn set_led_light_color_parameter_wire_format() {
+        assert_eq!(
+            serde_json::to_value(SetLedLightColorParameter {
+                r: 255,
+                g: 128,
+                b: 0
+            })
+            .unwrap(),
+            json!({ "r": 255, "g": 128, "b": 0 })
+        );
     }
 }
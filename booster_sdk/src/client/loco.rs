@@ -1,31 +1,140 @@
 //! High-level B1 locomotion client built on DDS RPC and topic I/O.
 
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, OnceLock};
 use std::time::Duration;
 
 use crate::dds::{
     BatteryState, BinaryData, ButtonEventMsg, DdsNode, DdsPublisher, DdsSubscription,
-    GripperControl, LightControlMsg, MotionState, RemoteControllerState, RobotProcessStateMsg,
-    RobotStatusDdsMsg, RpcClient, RpcClientOptions, SafeMode, battery_state_topic,
-    button_event_topic, device_gateway_topic, gripper_control_topic, light_control_topic,
-    motion_state_topic, process_state_topic, remote_controller_topic, safe_mode_topic,
-    video_stream_topic,
+    GripperControl, LatestSample, LightControlMsg, MotionState, RemoteControllerState,
+    RobotProcessStateMsg, RobotStatusDdsMsg, RpcClient, RpcClientOptions, SafeMode,
+    battery_state_topic, button_event_topic, device_gateway_topic, gripper_control_topic,
+    light_control_topic, motion_state_topic, process_state_topic, remote_controller_topic,
+    safe_mode_topic, video_stream_topic,
 };
+use crate::task::TaskHandle;
 use crate::types::{
-    BoosterHandType, CustomTrainedTraj, DanceId, DexterousFingerParameter, Frame, GetModeResponse,
+    Action, ActionEvent, ArmHomePose, BodyControl, BoosterError, BoosterHandType, CommandError,
+    CustomTrainedTraj, CustomTrajError, DanceId, DexterousFingerParameter, Frame, GetModeResponse,
     GetRobotInfoResponse, GetStatusResponse, GripperControlMode, GripperMode,
-    GripperMotionParameter, Hand, HandAction, HandIndex, LoadCustomTrainedTrajResponse, LocoApiId,
-    Result, RobotMode, Transform, WholeBodyDanceId,
+    GripperMotionParameter, Hand, HandAction, LoadCustomTrainedTrajResponse, LocoApiId,
+    MoveCommand, Position, Result, RobotMode, Transform, WholeBodyDanceId, head_angles_for_point,
 };
+use futures::Stream;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use typed_builder::TypedBuilder;
 
+use super::poll_until;
+
+/// Minimum timeout of [`BoosterClient::change_mode`]; mode switches are slow.
+const CHANGE_MODE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Update rate used by [`BoosterClient::move_ramped`].
+const MOVE_RAMP_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Refuse `command` with [`BoosterError::InvalidArgument`] if `mode` is damping.
+fn check_not_damping(command: &str, mode: i32) -> Result<()> {
+    if RobotMode::try_from(mode) == Ok(RobotMode::Damping) {
+        return Err(BoosterError::InvalidArgument(format!(
+            "{command}: robot is in Damping; call stand_up_into first"
+        )));
+    }
+    Ok(())
+}
+
+/// Reject a zero refresh period for [`BoosterClient::head_scan`].
+fn validate_head_scan_refresh(refresh: Duration) -> Result<()> {
+    if refresh.is_zero() {
+        return Err(BoosterError::Validation(
+            "head scan refresh period must be greater than zero".to_owned(),
+        ));
+    }
+    Ok(())
+}
+
+/// Reject robot-side file paths that cannot possibly name a file.
+///
+/// The path must be absolute because it is resolved by a service on the
+/// robot, whose working directory is unknown to the caller.
+fn validate_robot_file_path(kind: &str, path: &str) -> Result<()> {
+    let path = path.trim();
+    if path.is_empty() {
+        return Err(BoosterError::InvalidArgument(format!(
+            "{kind} path must not be empty"
+        )));
+    }
+    if !path.starts_with('/') {
+        return Err(BoosterError::InvalidArgument(format!(
+            "{kind} path '{path}' must be absolute"
+        )));
+    }
+    if path.ends_with('/') {
+        return Err(BoosterError::InvalidArgument(format!(
+            "{kind} path '{path}' points to a directory"
+        )));
+    }
+    Ok(())
+}
+
+/// Anything that can report the locomotion status, e.g. [`BoosterClient`].
+///
+/// Lets status-polling helpers such as [`await_action`] be shared between
+/// clients and exercised against a fake in tests.
+pub trait StatusSource {
+    fn get_status(&self) -> impl Future<Output = Result<GetStatusResponse>> + Send;
+}
+
+impl StatusSource for BoosterClient {
+    fn get_status(&self) -> impl Future<Output = Result<GetStatusResponse>> + Send {
+        BoosterClient::get_status(self)
+    }
+}
+
+/// Wait until `action` has been reported as running and then disappears.
+///
+/// Call this right after triggering the action: an action that finished
+/// before the first poll is never seen and ends in
+/// [`BoosterError::Timeout`].
+pub async fn await_action<C: StatusSource + ?Sized>(
+    client: &C,
+    action: Action,
+    timeout: Duration,
+) -> Result<()> {
+    let action = i32::from(action);
+    let mut started = false;
+    poll_status_until(client, timeout, |status| {
+        let running = status.current_actions.contains(&action);
+        started |= running;
+        started && !running
+    })
+    .await
+    .map(|_| ())
+}
+
+/// Poll `client` until `done` accepts a status; see [`poll_until`].
+async fn poll_status_until<C: StatusSource + ?Sized>(
+    client: &C,
+    timeout: Duration,
+    mut done: impl FnMut(&GetStatusResponse) -> bool,
+) -> Result<GetStatusResponse> {
+    poll_until(
+        timeout,
+        || client.get_status(),
+        |status| done(&status).then_some(Ok(status)),
+    )
+    .await
+}
+
 /// High-level client for B1 locomotion control and telemetry.
 pub struct BoosterClient {
     rpc: RpcClient,
     gripper_publisher: DdsPublisher<GripperControl>,
     light_publisher: DdsPublisher<LightControlMsg>,
     safe_mode_publisher: DdsPublisher<SafeMode>,
+    commanded_velocity: Mutex<MoveCommand>,
+    motion_state_cache: OnceLock<LatestSample<MotionState>>,
+    guard_mode: bool,
 }
 
 impl BoosterClient {
@@ -41,6 +150,7 @@ impl BoosterClient {
 
     /// Create a locomotion client with custom RPC options.
     pub fn with_options(options: RpcClientOptions) -> Result<Self> {
+        let guard_mode = options.guard_mode;
         let rpc = RpcClient::new(options)?;
         let node = rpc.node().clone();
         let gripper_publisher = node.publisher::<GripperControl>(&gripper_control_topic())?;
@@ -52,6 +162,9 @@ impl BoosterClient {
             gripper_publisher,
             light_publisher,
             safe_mode_publisher,
+            commanded_velocity: Mutex::new(MoveCommand::STOP),
+            motion_state_cache: OnceLock::new(),
+            guard_mode,
         })
     }
 
@@ -60,43 +173,178 @@ impl BoosterClient {
         self.rpc.node()
     }
 
+    /// Access the underlying RPC client, e.g. to call API ids not wrapped here.
+    pub fn rpc(&self) -> &RpcClient {
+        &self.rpc
+    }
+
     /// Change the robot mode.
+    ///
+    /// Waits at least 10 s for the reply, even inside a shorter
+    /// [`with_call_timeout`](crate::dds::with_call_timeout).
     pub async fn change_mode(&self, mode: RobotMode) -> Result<()> {
         let param = json!({ "mode": i32::from(mode) }).to_string();
-        self.rpc.call_void(LocoApiId::ChangeMode, param).await
+        self.rpc
+            .call_void_with_timeout(LocoApiId::ChangeMode, param, Some(CHANGE_MODE_TIMEOUT))
+            .await
     }
 
     /// Get the current robot mode.
+    ///
+    /// Retries briefly if the first call times out before discovery completes.
     pub async fn get_mode(&self) -> Result<GetModeResponse> {
-        self.rpc.call_response(LocoApiId::GetMode, "").await
+        self.rpc.call_query(LocoApiId::GetMode, &()).await
     }
 
     /// Get the current robot status.
+    ///
+    /// Retries briefly if the first call times out before discovery completes.
     pub async fn get_status(&self) -> Result<GetStatusResponse> {
-        self.rpc.call_response(LocoApiId::GetStatus, "").await
+        self.rpc.call_query(LocoApiId::GetStatus, &()).await
     }
 
     /// Get robot identity and version information.
+    ///
+    /// Retries briefly if the first call times out before discovery completes.
     pub async fn get_robot_info(&self) -> Result<GetRobotInfoResponse> {
-        self.rpc.call_response(LocoApiId::GetRobotInfo, "").await
+        self.rpc.call_query(LocoApiId::GetRobotInfo, &()).await
+    }
+
+    /// Check whether the robot is reachable and in a mode that accepts motion.
+    ///
+    /// Returns `Ok(true)` only in Walking or Custom mode, and `Err` only when
+    /// the service cannot be reached.
+    pub async fn is_ready(&self) -> Result<bool> {
+        let response = self.get_mode().await?;
+        Ok(response.mode_enum().is_some_and(RobotMode::accepts_motion))
     }
 
     /// Move the robot base in body frame.
     pub async fn move_robot(&self, vx: f32, vy: f32, vyaw: f32) -> Result<()> {
+        if MoveCommand::new(vx, vy, vyaw) != MoveCommand::STOP {
+            self.ensure_not_damping("move_robot").await?;
+        }
+        self.send_move(vx, vy, vyaw).await
+    }
+
+    async fn send_move(&self, vx: f32, vy: f32, vyaw: f32) -> Result<()> {
         let param = json!({ "vx": vx, "vy": vy, "vyaw": vyaw }).to_string();
-        self.rpc.call_void(LocoApiId::Move, param).await
+        self.rpc.call_void(LocoApiId::Move, param).await?;
+        *self
+            .commanded_velocity
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = MoveCommand::new(vx, vy, vyaw);
+        Ok(())
+    }
+
+    /// With [`RpcClientOptions::guard_mode`] enabled, refuse `command` while
+    /// the robot is damping.
+    ///
+    /// Stop commands (zero velocity, zero head direction, [`DanceId::Stop`])
+    /// skip this check. Uses the motion state cache when it already holds a
+    /// sample and falls back to `get_mode` otherwise. Unknown modes are let
+    /// through.
+    async fn ensure_not_damping(&self, command: &str) -> Result<()> {
+        if !self.guard_mode {
+            return Ok(());
+        }
+        let mode = match self.cached_mode() {
+            Some(mode) => mode,
+            None => self.get_mode().await?.mode,
+        };
+        check_not_damping(command, mode)
+    }
+
+    /// Like [`Self::ensure_not_damping`], but only consults the motion state
+    /// cache, so topic publishes stay synchronous. Without a cached sample
+    /// the command is let through.
+    fn ensure_not_damping_cached(&self, command: &str) -> Result<()> {
+        match self.cached_mode() {
+            Some(mode) if self.guard_mode => check_not_damping(command, mode),
+            _ => Ok(()),
+        }
+    }
+
+    fn cached_mode(&self) -> Option<i32> {
+        self.motion_state_cache
+            .get()
+            .and_then(LatestSample::get)
+            .map(|state| state.current_mode)
+    }
+
+    /// Last velocity successfully sent with [`Self::move_robot`].
+    ///
+    /// Starts at zero. Mode changes on the robot do not reset it.
+    pub fn commanded_velocity(&self) -> MoveCommand {
+        *self
+            .commanded_velocity
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Ramp linearly from the last commanded velocity to `target` over `ramp_time`.
+    ///
+    /// Sends intermediate `Move` commands every 50 ms and always finishes by
+    /// sending `target` itself. A zero `ramp_time` behaves like
+    /// [`Self::move_robot`].
+    pub async fn move_ramped(&self, target: MoveCommand, ramp_time: Duration) -> Result<()> {
+        if target != MoveCommand::STOP {
+            self.ensure_not_damping("move_ramped").await?;
+        }
+        let start = self.commanded_velocity();
+        let steps = ramp_time
+            .as_millis()
+            .div_ceil(MOVE_RAMP_INTERVAL.as_millis())
+            .max(1);
+        let steps = u32::try_from(steps).unwrap_or(u32::MAX);
+
+        for step in 1..=steps {
+            let command = start.lerp(target, step as f32 / steps as f32);
+            self.send_move(command.vx, command.vy, command.vyaw).await?;
+            if step < steps {
+                tokio::time::sleep(MOVE_RAMP_INTERVAL).await;
+            }
+        }
+        Ok(())
     }
 
     /// Rotate the head to absolute pitch/yaw angles.
     pub async fn rotate_head(&self, pitch: f32, yaw: f32) -> Result<()> {
+        self.ensure_not_damping("rotate_head").await?;
         let param = json!({ "pitch": pitch, "yaw": yaw }).to_string();
         self.rpc.call_void(LocoApiId::RotateHead, param).await
     }
 
+    /// Rotate the head back to looking straight ahead.
+    pub async fn center_head(&self) -> Result<()> {
+        self.rotate_head(0.0, 0.0).await
+    }
+
+    /// Rotate the head to absolute pitch/yaw angles given in degrees.
+    pub async fn look_at_degrees(&self, pitch_deg: f32, yaw_deg: f32) -> Result<()> {
+        self.rotate_head(pitch_deg.to_radians(), yaw_deg.to_radians())
+            .await
+    }
+
+    /// Aim the head at `point`, given in the body frame in meters.
+    ///
+    /// Queries the head origin with [`Self::get_frame_transform`] so nearby
+    /// points are aimed at from the head rather than the body origin.
+    pub async fn look_at_point(&self, point: Position) -> Result<()> {
+        let head = self.get_frame_transform(Frame::Body, Frame::Head).await?;
+        let (pitch, yaw) = head_angles_for_point(Position {
+            x: point.x - head.position.x,
+            y: point.y - head.position.y,
+            z: point.z - head.position.z,
+        });
+        self.rotate_head(pitch, yaw).await
+    }
+
     /// Trigger a right-hand wave action.
     pub async fn wave_hand(&self, action: HandAction) -> Result<()> {
+        self.ensure_not_damping("wave_hand").await?;
         let param = json!({
-            "hand_index": i32::from(HandIndex::Right),
+            "hand_index": Hand::Right.index(),
             "hand_action": i32::from(action),
         })
         .to_string();
@@ -109,6 +357,10 @@ impl BoosterClient {
         pitch_direction: i32,
         yaw_direction: i32,
     ) -> Result<()> {
+        if (pitch_direction, yaw_direction) != (0, 0) {
+            self.ensure_not_damping("rotate_head_with_direction")
+                .await?;
+        }
         let param = json!({
             "pitch_direction": pitch_direction,
             "yaw_direction": yaw_direction,
@@ -119,14 +371,53 @@ impl BoosterClient {
             .await
     }
 
+    /// Keep the head turning in the given directions until the handle is dropped.
+    ///
+    /// Resends [`Self::rotate_head_with_direction`] every `refresh` so the
+    /// firmware does not stop the motion, and sends a stop command `(0, 0)`
+    /// when the returned [`HeadScan`] is stopped or dropped. Must be called
+    /// inside a tokio runtime. Returns [`BoosterError::Validation`] if
+    /// `refresh` is zero.
+    pub fn head_scan(
+        self: &Arc<Self>,
+        pitch_direction: i32,
+        yaw_direction: i32,
+        refresh: Duration,
+    ) -> Result<HeadScan> {
+        validate_head_scan_refresh(refresh)?;
+        let client = Arc::clone(self);
+        let task = TaskHandle::spawn(async move {
+            let mut ticker = tokio::time::interval(refresh);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                if let Err(err) = client
+                    .rotate_head_with_direction(pitch_direction, yaw_direction)
+                    .await
+                {
+                    tracing::warn!(
+                        target: "booster_sdk::loco",
+                        error = %err,
+                        "head scan refresh failed"
+                    );
+                }
+            }
+        });
+        Ok(HeadScan {
+            client: Arc::clone(self),
+            task,
+            stopped: false,
+        })
+    }
+
     /// Command the robot to lie down.
     pub async fn lie_down(&self) -> Result<()> {
-        self.rpc.call_void(LocoApiId::LieDown, "").await
+        self.rpc.call_serialized(LocoApiId::LieDown, &()).await
     }
 
     /// Command the robot to get up.
     pub async fn get_up(&self) -> Result<()> {
-        self.rpc.call_void(LocoApiId::GetUp, "").await
+        self.rpc.call_serialized(LocoApiId::GetUp, &()).await
     }
 
     /// Command the robot to get up into a specific mode.
@@ -135,14 +426,46 @@ impl BoosterClient {
         self.rpc.call_void(LocoApiId::GetUpWithMode, param).await
     }
 
+    /// Get up into `mode` and wait until the robot reports it.
+    ///
+    /// Fails with [`CommandError::ModeNotReached`], naming the last reported
+    /// mode, if `mode` is not reached within `timeout`.
+    pub async fn stand_up_into(&self, mode: RobotMode, timeout: Duration) -> Result<()> {
+        self.get_up_with_mode(mode).await?;
+
+        let mut actual = None;
+        let reached = poll_status_until(self, timeout, |status| {
+            actual = Some(status.current_mode);
+            status.current_mode_enum() == Some(mode)
+        })
+        .await;
+        match reached {
+            Ok(_) => Ok(()),
+            Err(BoosterError::Timeout { .. }) => Err(CommandError::ModeNotReached {
+                target: format!("{mode:?}"),
+                actual: actual.map_or_else(
+                    || "unknown".to_owned(),
+                    |code| {
+                        RobotMode::try_from(code)
+                            .map_or_else(|_| code.to_string(), |mode| format!("{mode:?}"))
+                    },
+                ),
+            }
+            .into()),
+            Err(err) => Err(err),
+        }
+    }
+
     /// Trigger a shoot action.
     pub async fn shoot(&self) -> Result<()> {
-        self.rpc.call_void(LocoApiId::Shoot, "").await
+        self.ensure_not_damping("shoot").await?;
+        self.rpc.call_serialized(LocoApiId::Shoot, &()).await
     }
 
     /// Trigger a push-up action.
     pub async fn push_up(&self) -> Result<()> {
-        self.rpc.call_void(LocoApiId::PushUp, "").await
+        self.ensure_not_damping("push_up").await?;
+        self.rpc.call_serialized(LocoApiId::PushUp, &()).await
     }
 
     /// Move a hand end effector with auxiliary posture input.
@@ -151,13 +474,15 @@ impl BoosterClient {
         target_posture: &crate::types::Posture,
         aux_posture: &crate::types::Posture,
         time_millis: i32,
-        hand_index: HandIndex,
+        hand_index: Hand,
     ) -> Result<()> {
+        self.ensure_not_damping("move_hand_end_effector_with_aux")
+            .await?;
         let param = json!({
             "target_posture": target_posture,
             "aux_posture": aux_posture,
             "time_millis": time_millis,
-            "hand_index": i32::from(hand_index),
+            "hand_index": hand_index.index(),
             "has_aux": true,
             "new_version": false,
         })
@@ -172,12 +497,13 @@ impl BoosterClient {
         &self,
         target_posture: &crate::types::Posture,
         time_millis: i32,
-        hand_index: HandIndex,
+        hand_index: Hand,
     ) -> Result<()> {
+        self.ensure_not_damping("move_hand_end_effector").await?;
         let param = json!({
             "target_posture": target_posture,
             "time_millis": time_millis,
-            "hand_index": i32::from(hand_index),
+            "hand_index": hand_index.index(),
             "has_aux": false,
             "new_version": false,
         })
@@ -192,12 +518,13 @@ impl BoosterClient {
         &self,
         target_posture: &crate::types::Posture,
         time_millis: i32,
-        hand_index: HandIndex,
+        hand_index: Hand,
     ) -> Result<()> {
+        self.ensure_not_damping("move_hand_end_effector_v2").await?;
         let param = json!({
             "target_posture": target_posture,
             "time_millis": time_millis,
-            "hand_index": i32::from(hand_index),
+            "hand_index": hand_index.index(),
             "has_aux": false,
             "new_version": true,
         })
@@ -209,7 +536,9 @@ impl BoosterClient {
 
     /// Stop hand end-effector motion.
     pub async fn stop_hand_end_effector(&self) -> Result<()> {
-        self.rpc.call_void(LocoApiId::StopHandEndEffector, "").await
+        self.rpc
+            .call_serialized(LocoApiId::StopHandEndEffector, &())
+            .await
     }
 
     /// Control a gripper.
@@ -217,12 +546,13 @@ impl BoosterClient {
         &self,
         motion_param: GripperMotionParameter,
         mode: GripperControlMode,
-        hand_index: HandIndex,
+        hand_index: Hand,
     ) -> Result<()> {
+        self.ensure_not_damping("control_gripper").await?;
         let param = json!({
             "motion_param": motion_param,
             "mode": i32::from(mode),
-            "hand_index": i32::from(hand_index),
+            "hand_index": hand_index.index(),
         })
         .to_string();
         self.rpc.call_void(LocoApiId::ControlGripper, param).await
@@ -250,6 +580,7 @@ impl BoosterClient {
 
     /// Trigger a handshake action.
     pub async fn handshake(&self, action: HandAction) -> Result<()> {
+        self.ensure_not_damping("handshake").await?;
         let param = json!({ "hand_action": i32::from(action) }).to_string();
         self.rpc.call_void(LocoApiId::Handshake, param).await
     }
@@ -258,12 +589,13 @@ impl BoosterClient {
     pub async fn control_dexterous_hand(
         &self,
         finger_params: &[DexterousFingerParameter],
-        hand_index: HandIndex,
+        hand_index: Hand,
         hand_type: BoosterHandType,
     ) -> Result<()> {
+        self.ensure_not_damping("control_dexterous_hand").await?;
         let param = json!({
             "finger_params": finger_params,
-            "hand_index": i32::from(hand_index),
+            "hand_index": hand_index.index(),
             "hand_type": i32::from(hand_type),
         })
         .to_string();
@@ -276,7 +608,7 @@ impl BoosterClient {
     pub async fn control_dexterous_hand_default(
         &self,
         finger_params: &[DexterousFingerParameter],
-        hand_index: HandIndex,
+        hand_index: Hand,
     ) -> Result<()> {
         self.control_dexterous_hand(finger_params, hand_index, BoosterHandType::InspireHand)
             .await
@@ -284,19 +616,39 @@ impl BoosterClient {
 
     /// Trigger an upper-body dance or gesture action.
     pub async fn dance(&self, dance_id: DanceId) -> Result<()> {
+        if dance_id != DanceId::Stop {
+            self.ensure_not_damping("dance").await?;
+        }
         let param = json!({ "dance_id": i32::from(dance_id) }).to_string();
         self.rpc.call_void(LocoApiId::Dance, param).await
     }
 
     /// Play a sound file on the robot.
+    ///
+    /// The path refers to a file on the robot and must be absolute. Neither
+    /// existence nor format is checked, because no service API exposes the
+    /// robot filesystem or the formats its player supports.
     pub async fn play_sound(&self, sound_file_path: impl Into<String>) -> Result<()> {
-        let param = json!({ "sound_file_path": sound_file_path.into() }).to_string();
+        let sound_file_path = sound_file_path.into();
+        validate_robot_file_path("sound file", &sound_file_path)?;
+        let param = json!({ "sound_file_path": sound_file_path }).to_string();
         self.rpc.call_void(LocoApiId::PlaySound, param).await
     }
 
+    /// Play a sound file after stopping any sound that is still playing.
+    ///
+    /// The locomotion service does not document whether overlapping
+    /// `play_sound` requests are mixed or queued, so sequential cues can pile
+    /// up. This issues `stop_sound` first; `play_sound` keeps the
+    /// non-exclusive behavior.
+    pub async fn play_sound_exclusive(&self, sound_file_path: impl Into<String>) -> Result<()> {
+        self.stop_sound().await?;
+        self.play_sound(sound_file_path).await
+    }
+
     /// Stop active sound playback.
     pub async fn stop_sound(&self) -> Result<()> {
-        self.rpc.call_void(LocoApiId::StopSound, "").await
+        self.rpc.call_serialized(LocoApiId::StopSound, &()).await
     }
 
     /// Enable or disable zero-torque drag mode.
@@ -312,13 +664,18 @@ impl BoosterClient {
     }
 
     /// Replay a recorded trajectory from file.
+    ///
+    /// The path refers to a file on the robot and must be absolute.
     pub async fn replay_trajectory(&self, traj_file_path: impl Into<String>) -> Result<()> {
-        let param = json!({ "traj_file_path": traj_file_path.into() }).to_string();
+        let traj_file_path = traj_file_path.into();
+        validate_robot_file_path("trajectory file", &traj_file_path)?;
+        let param = json!({ "traj_file_path": traj_file_path }).to_string();
         self.rpc.call_void(LocoApiId::ReplayTrajectory, param).await
     }
 
     /// Trigger a whole-body dance action.
     pub async fn whole_body_dance(&self, dance_id: WholeBodyDanceId) -> Result<()> {
+        self.ensure_not_damping("whole_body_dance").await?;
         let param = json!({ "dance_id": i32::from(dance_id) }).to_string();
         self.rpc.call_void(LocoApiId::WholeBodyDance, param).await
     }
@@ -333,17 +690,33 @@ impl BoosterClient {
 
     /// Reset odometry state.
     pub async fn reset_odometry(&self) -> Result<()> {
-        self.rpc.call_void(LocoApiId::ResetOdometry, "").await
+        self.rpc
+            .call_serialized(LocoApiId::ResetOdometry, &())
+            .await
     }
 
     /// Load a custom trained trajectory.
+    ///
+    /// Every model parameter set is checked with [`CustomModelParams::validate`]
+    /// against the model's joint order first. Recognized service failures are
+    /// returned as [`BoosterError::CustomTraj`] instead of a plain RPC error.
+    ///
+    /// [`CustomModelParams::validate`]: crate::types::CustomModelParams::validate
     pub async fn load_custom_trained_traj(
         &self,
         traj: &CustomTrainedTraj,
     ) -> Result<LoadCustomTrainedTrajResponse> {
+        for params in &traj.model.params {
+            params.validate(traj.model.joint_order)?;
+        }
         self.rpc
             .call_serialized_response(LocoApiId::LoadCustomTrainedTraj, traj)
             .await
+            .map_err(|err| match err {
+                BoosterError::Rpc(rpc) => CustomTrajError::from_rpc(&rpc)
+                    .map_or(BoosterError::Rpc(rpc), BoosterError::CustomTraj),
+                other => other,
+            })
     }
 
     /// Activate a loaded custom trained trajectory by id.
@@ -364,12 +737,80 @@ impl BoosterClient {
 
     /// Enter WBC gait mode.
     pub async fn enter_wbc_gait(&self) -> Result<()> {
-        self.rpc.call_void(LocoApiId::EnterWbcGait, "").await
+        self.rpc.call_serialized(LocoApiId::EnterWbcGait, &()).await
     }
 
     /// Exit WBC gait mode.
     pub async fn exit_wbc_gait(&self) -> Result<()> {
-        self.rpc.call_void(LocoApiId::ExitWbcGait, "").await
+        self.rpc.call_serialized(LocoApiId::ExitWbcGait, &()).await
+    }
+
+    /// Wait until the robot reports `mode` as its current mode.
+    ///
+    /// Polls `get_status` and returns [`BoosterError::Timeout`] if the mode
+    /// has not switched within `timeout`.
+    pub async fn wait_for_mode(&self, mode: RobotMode, timeout: Duration) -> Result<()> {
+        poll_status_until(self, timeout, |status| {
+            status.current_mode_enum() == Some(mode)
+        })
+        .await
+        .map(|_| ())
+    }
+
+    /// Wait until the robot reports `target` as its current body control.
+    ///
+    /// Polls `get_status` and returns [`BoosterError::Timeout`] if the body
+    /// control has not switched within `timeout`. Useful after
+    /// [`Self::enter_wbc_gait`] or [`Self::exit_wbc_gait`].
+    pub async fn wait_for_body_control(
+        &self,
+        target: BodyControl,
+        timeout: Duration,
+    ) -> Result<()> {
+        poll_status_until(self, timeout, |status| {
+            status.current_body_control_enum() == Some(target)
+        })
+        .await
+        .map(|_| ())
+    }
+
+    /// Stream action start/finish events by polling `get_status` every `interval`.
+    ///
+    /// The first poll is compared against an empty set, so actions already
+    /// running are reported as started. A failed poll yields its error and
+    /// polling continues on the next tick.
+    pub fn action_events(
+        &self,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<ActionEvent>> + '_ {
+        let state = (Vec::new(), VecDeque::new(), true);
+        futures::stream::unfold(
+            state,
+            move |(mut previous, mut pending, mut first)| async move {
+                loop {
+                    if let Some(event) = pending.pop_front() {
+                        return Some((Ok(event), (previous, pending, first)));
+                    }
+                    if !first {
+                        tokio::time::sleep(interval).await;
+                    }
+                    first = false;
+                    match self.get_status().await {
+                        Ok(status) => {
+                            let current = status.current_actions_enum();
+                            pending.extend(ActionEvent::diff(&previous, &current));
+                            previous = current;
+                        }
+                        Err(err) => return Some((Err(err), (previous, pending, first))),
+                    }
+                }
+            },
+        )
+    }
+
+    /// Wait for `action` to run and finish; see [`await_action`].
+    pub async fn await_action(&self, action: Action, timeout: Duration) -> Result<()> {
+        await_action(self, action, timeout).await
     }
 
     /// Move both hand end-effectors to target postures simultaneously.
@@ -379,6 +820,8 @@ impl BoosterClient {
         right_target_posture: &crate::types::Posture,
         time_millis: i32,
     ) -> Result<()> {
+        self.ensure_not_damping("move_dual_hand_end_effector")
+            .await?;
         let param = json!({
             "left_target_posture": left_target_posture,
             "right_target_posture": right_target_posture,
@@ -390,19 +833,63 @@ impl BoosterClient {
             .await
     }
 
+    /// Move both hands to the neutral `pose`.
+    ///
+    /// Like the other end-effector commands this only has an effect while the
+    /// robot is in a mode that accepts hand commands, so it is ignored in
+    /// damping or prepare mode.
+    pub async fn home_arms(&self, pose: &ArmHomePose) -> Result<()> {
+        self.move_dual_hand_end_effector(&pose.left, &pose.right, pose.time_millis)
+            .await
+    }
+
+    /// Stop the base and optionally return the arms to a neutral pose.
+    ///
+    /// Intended as the last call of a script. The base is always stopped
+    /// first; with `home_pose` set, [`Self::home_arms`] runs next.
+    pub async fn halt(&self, home_pose: Option<&ArmHomePose>) -> Result<()> {
+        self.send_move(0.0, 0.0, 0.0).await?;
+        if let Some(pose) = home_pose {
+            self.home_arms(pose).await?;
+        }
+        Ok(())
+    }
+
     /// Start or stop a visual kick (side-foot kick).
     pub async fn visual_kick(&self, start: bool) -> Result<()> {
+        self.ensure_not_damping("visual_kick").await?;
         let param = json!({ "start": start }).to_string();
         self.rpc.call_void(LocoApiId::VisualKick, param).await
     }
 
+    /// Send a raw JSON request to the locomotion service and return the raw response body.
+    ///
+    /// This is an escape hatch for API ids that are not wrapped yet. An empty
+    /// body is sent as-is; any other body must be valid JSON.
+    pub async fn call_raw(&self, api_id: i32, body: impl Into<String>) -> Result<String> {
+        let body = body.into();
+        if !body.trim().is_empty() {
+            serde_json::from_str::<serde_json::Value>(&body).map_err(|err| {
+                BoosterError::Validation(format!("request body is not valid JSON: {err}"))
+            })?;
+        }
+        self.rpc.call_raw(api_id, body, None).await
+    }
+
     /// Publish a raw gripper control topic message.
+    ///
+    /// With [`RpcClientOptions::guard_mode`] enabled, this is refused while
+    /// the motion state cache reports damping.
     pub fn publish_gripper(&self, control: GripperControl) -> Result<()> {
+        self.ensure_not_damping_cached("publish_gripper")?;
         self.gripper_publisher.write(control)
     }
 
     /// Publish a high-level gripper command.
+    ///
+    /// Guarded like [`Self::publish_gripper`].
     pub fn publish_gripper_command(&self, command: &GripperCommand) -> Result<()> {
+        self.ensure_not_damping_cached("publish_gripper_command")?;
         self.gripper_publisher.write(command.to_dds_control())
     }
 
@@ -417,6 +904,8 @@ impl BoosterClient {
     }
 
     /// Subscribe to device gateway robot status messages.
+    ///
+    /// Use [`RobotStatusDdsMsg::decode`] for a typed health view of each sample.
     pub fn subscribe_device_gateway(&self) -> Result<DdsSubscription<RobotStatusDdsMsg>> {
         self.rpc.node().subscribe(&device_gateway_topic(), 32)
     }
@@ -426,6 +915,27 @@ impl BoosterClient {
         self.rpc.node().subscribe(&motion_state_topic(), 16)
     }
 
+    /// Shared cache of the newest motion state sample.
+    ///
+    /// The first call starts a single background subscription that lives as
+    /// long as the client; later calls return the same cache. Prefer this
+    /// over [`Self::subscribe_motion_state`] when only the current state is
+    /// needed.
+    pub fn motion_state_cache(&self) -> Result<&LatestSample<MotionState>> {
+        if let Some(cache) = self.motion_state_cache.get() {
+            return Ok(cache);
+        }
+        let cache = self.subscribe_motion_state()?.into_latest()?;
+        Ok(self.motion_state_cache.get_or_init(|| cache))
+    }
+
+    /// Newest motion state seen by [`Self::motion_state_cache`].
+    ///
+    /// Returns `Ok(None)` until the first sample has arrived.
+    pub fn latest_motion_state(&self) -> Result<Option<MotionState>> {
+        Ok(self.motion_state_cache()?.get())
+    }
+
     /// Subscribe to battery state messages.
     pub fn subscribe_battery_state(&self) -> Result<DdsSubscription<BatteryState>> {
         self.rpc.node().subscribe(&battery_state_topic(), 8)
@@ -454,6 +964,7 @@ impl BoosterClient {
 
 /// Gripper control command
 #[derive(Debug, Clone, Copy, TypedBuilder, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GripperCommand {
     /// Target hand
     pub hand: Hand,
@@ -521,3 +1032,131 @@ impl GripperCommand {
         }
     }
 }
+
+/// Continuous head motion started by [`BoosterClient::head_scan`].
+#[must_use = "dropping a HeadScan stops the head"]
+pub struct HeadScan {
+    client: Arc<BoosterClient>,
+    task: TaskHandle,
+    stopped: bool,
+}
+
+impl HeadScan {
+    /// Stop refreshing and wait for the stop command to be acknowledged.
+    pub async fn stop(mut self) -> Result<()> {
+        self.task.stop();
+        self.stopped = true;
+        self.client.rotate_head_with_direction(0, 0).await
+    }
+}
+
+impl Drop for HeadScan {
+    fn drop(&mut self) {
+        self.task.stop();
+        if self.stopped {
+            return;
+        }
+        // Drop cannot wait for the reply, so send the stop in the background.
+        // This one-shot command must outlive the handle, so unlike the refresh
+        // loop it is not owned by a TaskHandle; the RPC timeout bounds it.
+        let client = Arc::clone(&self.client);
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            runtime.spawn(async move {
+                if let Err(err) = client.rotate_head_with_direction(0, 0).await {
+                    tracing::warn!(
+                        target: "booster_sdk::loco",
+                        error = %err,
+                        "failed to stop head scan"
+                    );
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct ScriptedStatus(Mutex<VecDeque<Vec<i32>>>);
+
+    impl ScriptedStatus {
+        fn new(steps: &[&[i32]]) -> Self {
+            Self(Mutex::new(steps.iter().map(|step| step.to_vec()).collect()))
+        }
+    }
+
+    impl StatusSource for ScriptedStatus {
+        /// Replay the scripted action lists, repeating the last one forever.
+        fn get_status(&self) -> impl Future<Output = Result<GetStatusResponse>> + Send {
+            let mut steps = self.0.lock().unwrap();
+            let current_actions = if steps.len() > 1 {
+                steps.pop_front().unwrap()
+            } else {
+                steps.front().cloned().unwrap_or_default()
+            };
+            std::future::ready(Ok(GetStatusResponse {
+                current_mode: i32::from(RobotMode::Walking),
+                current_body_control: 0,
+                current_actions,
+            }))
+        }
+    }
+
+    #[tokio::test]
+    async fn await_action_waits_for_action_to_start_and_finish() {
+        let wave = i32::from(Action::HandWave);
+        let source = ScriptedStatus::new(&[&[], &[wave], &[wave, 1], &[]]);
+        await_action(&source, Action::HandWave, Duration::from_secs(2))
+            .await
+            .unwrap();
+        assert!(source.0.lock().unwrap().front().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn await_action_times_out_if_action_never_runs() {
+        let source = ScriptedStatus::new(&[&[]]);
+        let result = await_action(&source, Action::HandWave, Duration::from_millis(250)).await;
+        assert!(matches!(result, Err(BoosterError::Timeout { .. })));
+    }
+
+    #[test]
+    fn damping_guard_names_the_fix() {
+        assert!(check_not_damping("move_robot", i32::from(RobotMode::Walking)).is_ok());
+        assert!(check_not_damping("move_robot", 99).is_ok());
+        match check_not_damping("move_robot", i32::from(RobotMode::Damping)) {
+            Err(BoosterError::InvalidArgument(message)) => {
+                assert!(message.contains("robot is in Damping"), "{message}");
+            }
+            other => panic!("expected invalid argument, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn head_scan_rejects_zero_refresh() {
+        assert!(matches!(
+            validate_head_scan_refresh(Duration::ZERO),
+            Err(BoosterError::Validation(_))
+        ));
+        assert!(validate_head_scan_refresh(Duration::from_millis(1)).is_ok());
+    }
+
+    #[test]
+    fn robot_file_path_rejects_empty_relative_and_directories() {
+        for path in ["", "   ", "hello.wav", "/home/booster/"] {
+            assert!(
+                matches!(
+                    validate_robot_file_path("sound file", path),
+                    Err(BoosterError::InvalidArgument(_))
+                ),
+                "{path:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn robot_file_path_accepts_any_absolute_file() {
+        assert!(validate_robot_file_path("sound file", "/tmp/hello.wav").is_ok());
+        assert!(validate_robot_file_path("trajectory file", "/tmp/traj").is_ok());
+    }
+}
@@ -1,6 +1,7 @@
 //! High-level client APIs for the Booster Robotics SDK.
 
 pub mod ai;
+pub mod fleet;
 pub mod light_control;
 pub mod loco;
 pub mod vision;
@@ -8,7 +9,42 @@ pub mod x5_camera;
 
 pub use loco::BoosterClient;
 
-/// Declare an i32-backed enum with serde, `From<i32>`, and `TryFrom<i32>`.
+use std::time::Duration;
+
+use crate::types::{BoosterError, Result};
+
+/// Interval between status polls in the wait helpers.
+pub(crate) const STATUS_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Call `poll` every [`STATUS_POLL_INTERVAL`] until `check` returns a result.
+///
+/// Errors from `poll` are returned immediately. Fails with
+/// [`BoosterError::Timeout`] once the next poll would start after `timeout`.
+pub(crate) async fn poll_until<T, R, Fut>(
+    timeout: Duration,
+    mut poll: impl FnMut() -> Fut,
+    mut check: impl FnMut(T) -> Option<Result<R>>,
+) -> Result<R>
+where
+    Fut: Future<Output = Result<T>>,
+{
+    let deadline = tokio::time::Instant::now() + timeout;
+    loop {
+        if let Some(result) = check(poll().await?) {
+            return result;
+        }
+        if tokio::time::Instant::now() + STATUS_POLL_INTERVAL > deadline {
+            return Err(BoosterError::timeout(timeout));
+        }
+        tokio::time::sleep(STATUS_POLL_INTERVAL).await;
+    }
+}
+
+/// Declare an i32-backed enum with serde, `From<i32>`, `TryFrom<i32>`,
+/// `FromStr` and an `ALL` list of its variants.
+///
+/// `FromStr` matches variant names case-insensitively and ignores `_` and
+/// `-`, so `towards_future` parses as `TowardsFuture`.
 ///
 /// Default form makes the enum `pub`:
 /// `api_id_enum! { Name { A = 1, B = 2 } }`
@@ -96,23 +132,64 @@ macro_rules! api_id_enum {
             )+
         }
 
+        impl $name {
+            /// Every variant, in declaration order.
+            pub const ALL: &'static [Self] = &[$(Self::$variant),+];
+        }
+
         impl From<$name> for i32 {
             fn from(value: $name) -> Self {
                 value as i32
             }
         }
 
+        impl std::str::FromStr for $name {
+            type Err = $crate::types::UnknownEnumName;
+
+            fn from_str(name: &str) -> std::result::Result<Self, Self::Err> {
+                $(
+                    if $crate::client::enum_name_matches(stringify!($variant), name) {
+                        return Ok(Self::$variant);
+                    }
+                )+
+                Err($crate::types::UnknownEnumName {
+                    enum_name: stringify!($name),
+                    name: name.to_owned(),
+                })
+            }
+        }
+
         impl TryFrom<i32> for $name {
-            type Error = &'static str;
+            type Error = $crate::types::UnknownEnumValue;
 
             fn try_from(value: i32) -> std::result::Result<Self, Self::Error> {
-                match value {
+                let parsed = match value {
                     $(
-                        $value => Ok(Self::$variant),
+                        $value => Self::$variant,
                     )+
-                    _ => Err("invalid value"),
-                }
+                    _ => {
+                        return Err($crate::types::UnknownEnumValue {
+                            enum_name: stringify!($name),
+                            value,
+                        });
+                    }
+                };
+                debug_assert_eq!(i32::from(parsed), value, "discriminant mismatch");
+                Ok(parsed)
             }
         }
     };
 }
+
+/// Whether `name` spells `variant`, ignoring ASCII case, `_` and `-`.
+#[doc(hidden)]
+#[must_use]
+pub fn enum_name_matches(variant: &str, name: &str) -> bool {
+    let normalize = |text: &str| {
+        text.chars()
+            .filter(|c| !matches!(c, '_' | '-'))
+            .map(|c| c.to_ascii_lowercase())
+            .collect::<Vec<_>>()
+    };
+    normalize(variant) == normalize(name)
+}
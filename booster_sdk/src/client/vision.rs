@@ -4,9 +4,10 @@ use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use typed_builder::TypedBuilder;
 
-use crate::dds::{RpcClient, RpcClientOptions, VISION_API_TOPIC};
-use crate::types::Result;
+use crate::dds::{RpcClient, RpcClientOptions, VISION_API_TOPIC, rpc::preview_for_log};
+use crate::types::{BoosterError, NamedColor, Result, RpcError};
 
 crate::api_id_enum! {
     /// Vision service RPC API identifiers.
@@ -18,15 +19,43 @@ crate::api_id_enum! {
 }
 
 /// Parameters for starting the vision service.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// All features default to disabled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, TypedBuilder, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct StartVisionServiceParameter {
+    #[builder(default)]
     pub enable_position: bool,
+    #[builder(default)]
     pub enable_color: bool,
+    #[builder(default)]
     pub enable_face_detection: bool,
 }
 
+impl StartVisionServiceParameter {
+    /// Enable only position estimation.
+    #[must_use]
+    pub fn position_only() -> Self {
+        Self {
+            enable_position: true,
+            ..Self::default()
+        }
+    }
+
+    /// Enable position, color and face detection.
+    #[must_use]
+    pub fn all_features() -> Self {
+        Self {
+            enable_position: true,
+            enable_color: true,
+            enable_face_detection: true,
+        }
+    }
+}
+
 /// Parameters for object detection requests.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GetDetectionObjectParameter {
     pub focus_ratio: f32,
 }
@@ -39,6 +68,7 @@ impl Default for GetDetectionObjectParameter {
 
 /// Single vision detection result.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct DetectResults {
     pub xmin: i64,
     pub ymin: i64,
@@ -50,6 +80,32 @@ pub struct DetectResults {
     pub rgb_mean: Vec<i32>,
 }
 
+impl DetectResults {
+    /// Mean color of the detection as an RGB triple.
+    ///
+    /// `None` unless color output was enabled and `rgb_mean` holds exactly
+    /// three values in `0..=255`.
+    #[must_use]
+    pub fn rgb(&self) -> Option<(u8, u8, u8)> {
+        let [r, g, b] = self.rgb_mean.as_slice() else {
+            return None;
+        };
+        Some((
+            u8::try_from(*r).ok()?,
+            u8::try_from(*g).ok()?,
+            u8::try_from(*b).ok()?,
+        ))
+    }
+
+    /// Coarse color bucket of [`Self::rgb`], or [`NamedColor::Unknown`].
+    #[must_use]
+    pub fn dominant_color(&self) -> NamedColor {
+        self.rgb().map_or(NamedColor::Unknown, |(r, g, b)| {
+            NamedColor::classify(r, g, b)
+        })
+    }
+}
+
 /// High-level RPC client for vision inference APIs.
 pub struct VisionClient {
     rpc: RpcClient,
@@ -74,6 +130,11 @@ impl VisionClient {
         Ok(Self { rpc })
     }
 
+    /// Access the underlying RPC client, e.g. to call API ids not wrapped here.
+    pub fn rpc(&self) -> &RpcClient {
+        &self.rpc
+    }
+
     /// Start the vision service with selected features.
     pub async fn start_vision_service(
         &self,
@@ -81,11 +142,19 @@ impl VisionClient {
         enable_color: bool,
         enable_face_detection: bool,
     ) -> Result<()> {
-        let param = StartVisionServiceParameter {
+        self.start_vision_service_with(StartVisionServiceParameter {
             enable_position,
             enable_color,
             enable_face_detection,
-        };
+        })
+        .await
+    }
+
+    /// Start the vision service with a prepared parameter set.
+    pub async fn start_vision_service_with(
+        &self,
+        param: StartVisionServiceParameter,
+    ) -> Result<()> {
         self.rpc
             .call_serialized(VisionApiId::StartVisionService, &param)
             .await
@@ -94,11 +163,14 @@ impl VisionClient {
     /// Stop the vision service.
     pub async fn stop_vision_service(&self) -> Result<()> {
         self.rpc
-            .call_void(VisionApiId::StopVisionService, "{}")
+            .call_serialized(VisionApiId::StopVisionService, &())
             .await
     }
 
     /// Fetch detected objects with a custom focus ratio.
+    ///
+    /// Returns an error when the reply is neither a list of detections nor an
+    /// `{"objects": [...]}` wrapper, instead of silently returning nothing.
     pub async fn get_detection_object_with_ratio(
         &self,
         focus_ratio: f32,
@@ -108,16 +180,7 @@ impl VisionClient {
             .rpc
             .call_serialized_response(VisionApiId::GetDetectionObject, &param)
             .await?;
-
-        if value.is_array() {
-            return Ok(serde_json::from_value(value)?);
-        }
-
-        if let Some(objects) = value.get("objects") {
-            return Ok(serde_json::from_value(objects.clone())?);
-        }
-
-        Ok(Vec::new())
+        parse_detection_objects(value)
     }
 
     /// Fetch detected objects with the default focus ratio.
@@ -126,3 +189,103 @@ impl VisionClient {
             .await
     }
 }
+
+/// Accept a bare array, an `{"objects": [...]}` wrapper, or an empty reply.
+///
+/// Any other shape is reported as an error instead of "no detections".
+fn parse_detection_objects(value: Value) -> Result<Vec<DetectResults>> {
+    let objects = match value {
+        Value::Null => return Ok(Vec::new()),
+        Value::Array(_) => value,
+        Value::Object(mut map) if map.contains_key("objects") => {
+            map.remove("objects").unwrap_or(Value::Null)
+        }
+        Value::Object(map) if map.is_empty() => return Ok(Vec::new()),
+        other => return Err(unexpected_detection_payload(&other, "unexpected shape")),
+    };
+
+    serde_json::from_value(objects.clone())
+        .map_err(|err| unexpected_detection_payload(&objects, &err.to_string()))
+}
+
+fn unexpected_detection_payload(value: &Value, reason: &str) -> BoosterError {
+    RpcError::RequestFailed {
+        status: 0,
+        message: format!(
+            "Failed to parse detection objects ({reason}): {}",
+            preview_for_log(&value.to_string(), 200)
+        ),
+    }
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn detection() -> Value {
+        json!({
+            "xmin": 1, "ymin": 2, "xmax": 3, "ymax": 4,
+            "position": [0.5, 0.0, 1.0],
+            "tag": "ball",
+            "conf": 0.9,
+            "rgb_mean": [255, 255, 255],
+        })
+    }
+
+    #[test]
+    fn detection_objects_accept_array_and_wrapper() {
+        let bare = parse_detection_objects(json!([detection()])).unwrap();
+        assert_eq!(bare.len(), 1);
+        assert_eq!(bare[0].tag, "ball");
+
+        let wrapped = parse_detection_objects(json!({ "objects": [detection()] })).unwrap();
+        assert_eq!(wrapped, bare);
+    }
+
+    #[test]
+    fn dominant_color_requires_valid_rgb_mean() {
+        let mut result: DetectResults = serde_json::from_value(detection()).unwrap();
+        assert_eq!(result.rgb(), Some((255, 255, 255)));
+        assert_eq!(result.dominant_color(), NamedColor::White);
+
+        result.rgb_mean = vec![];
+        assert_eq!(result.dominant_color(), NamedColor::Unknown);
+        result.rgb_mean = vec![300, 0, 0];
+        assert_eq!(result.rgb(), None);
+    }
+
+    #[test]
+    fn detection_objects_treat_empty_reply_as_no_detections() {
+        assert!(parse_detection_objects(json!({})).unwrap().is_empty());
+        assert!(parse_detection_objects(Value::Null).unwrap().is_empty());
+        assert!(parse_detection_objects(json!([])).unwrap().is_empty());
+    }
+
+    #[test]
+    fn detection_objects_reject_malformed_payloads() {
+        let err = parse_detection_objects(json!({ "error": "camera offline" })).unwrap_err();
+        assert!(err.to_string().contains("camera offline"));
+
+        assert!(parse_detection_objects(json!("oops")).is_err());
+        assert!(parse_detection_objects(json!([{ "tag": "ball" }])).is_err());
+        assert!(parse_detection_objects(json!({ "objects": 5 })).is_err());
+    }
+
+    #[test]
+    fn parameters_wire_format() {
+        assert_eq!(
+            serde_json::to_value(StartVisionServiceParameter::position_only()).unwrap(),
+            json!({
+                "enable_position": true,
+                "enable_color": false,
+                "enable_face_detection": false,
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(GetDetectionObjectParameter { focus_ratio: 0.5 }).unwrap(),
+            json!({ "focus_ratio": 0.5 })
+        );
+    }
+}
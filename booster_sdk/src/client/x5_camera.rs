@@ -5,7 +5,9 @@ use std::time::Duration;
 use serde::{Deserialize, Serialize};
 
 use crate::dds::{RpcClient, RpcClientOptions, X5_CAMERA_CONTROL_API_TOPIC};
-use crate::types::Result;
+use crate::types::{Result, RpcError};
+
+use super::poll_until;
 
 crate::api_id_enum! {
     /// X5 camera RPC API identifiers.
@@ -25,6 +27,21 @@ crate::api_id_enum! {
     }
 }
 
+impl CameraSetMode {
+    /// Status the camera reports once this mode is active.
+    #[must_use]
+    pub fn expected_status(self) -> CameraControlStatus {
+        match self {
+            Self::CameraModeNormal | Self::CameraModeNormalEnable => {
+                CameraControlStatus::CameraStatusNormal
+            }
+            Self::CameraModeHighResolution | Self::CameraModeHighResolutionEnable => {
+                CameraControlStatus::CameraStatusHighResolution
+            }
+        }
+    }
+}
+
 crate::api_id_enum! {
     /// Reported X5 camera status values.
     CameraControlStatus {
@@ -37,12 +54,14 @@ crate::api_id_enum! {
 
 /// Parameters for camera mode changes.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ChangeModeParameter {
     pub mode: i32,
 }
 
 /// Response payload for camera status requests.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GetStatusResponse {
     pub status: i32,
 }
@@ -80,6 +99,11 @@ impl X5CameraClient {
         Ok(Self { rpc })
     }
 
+    /// Access the underlying RPC client, e.g. to call API ids not wrapped here.
+    pub fn rpc(&self) -> &RpcClient {
+        &self.rpc
+    }
+
     /// Change the camera mode.
     pub async fn change_mode(&self, mode: CameraSetMode) -> Result<()> {
         let param = ChangeModeParameter {
@@ -90,8 +114,67 @@ impl X5CameraClient {
             .await
     }
 
+    /// Change the camera mode and wait until the camera reports it.
+    ///
+    /// Polls [`Self::get_status`] until it matches
+    /// [`CameraSetMode::expected_status`]. Fails with
+    /// [`RpcError::StateTransitionFailed`] if the camera reports an error and
+    /// with [`BoosterError::Timeout`] if the mode is not reached in time.
+    pub async fn change_mode_confirmed(
+        &self,
+        mode: CameraSetMode,
+        timeout: Duration,
+    ) -> Result<()> {
+        let expected = mode.expected_status();
+        self.change_mode(mode).await?;
+
+        poll_until(
+            timeout,
+            || self.get_status(),
+            |status| match status.status_enum() {
+                Some(status) if status == expected => Some(Ok(())),
+                Some(CameraControlStatus::CameraStatusError) => {
+                    Some(Err(RpcError::StateTransitionFailed(format!(
+                        "camera reported an error while switching to {mode:?}"
+                    ))
+                    .into()))
+                }
+                _ => None,
+            },
+        )
+        .await
+    }
+
     /// Read the current camera status.
     pub async fn get_status(&self) -> Result<GetStatusResponse> {
-        self.rpc.call_response(X5CameraApiId::GetStatus, "").await
+        self.rpc
+            .call_serialized_response(X5CameraApiId::GetStatus, &())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn set_mode_maps_to_expected_status() {
+        assert_eq!(
+            CameraSetMode::CameraModeNormalEnable.expected_status(),
+            CameraControlStatus::CameraStatusNormal
+        );
+        assert_eq!(
+            CameraSetMode::CameraModeHighResolution.expected_status(),
+            CameraControlStatus::CameraStatusHighResolution
+        );
+    }
+
+    #[test]
+    fn change_mode_parameter_wire_format() {
+        assert_eq!(
+            serde_json::to_value(ChangeModeParameter { mode: 1 }).unwrap(),
+            json!({ "mode": 1 })
+        );
     }
 }
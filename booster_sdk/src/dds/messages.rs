@@ -2,6 +2,11 @@
 
 use serde::{Deserialize, Serialize};
 
+/// RPC request as sent on `<service>Req` topics.
+///
+/// `header` is a JSON object with an integer `api_id`, e.g. `{"api_id":2000}`,
+/// and `body` is the JSON-encoded parameters. Peers that send `api_id` as a
+/// string are not understood by the robot.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RpcReqMsg {
     pub uuid: String,
@@ -51,6 +56,131 @@ pub struct RobotStatusDdsMsg {
     pub battery_vec: Vec<RobotDdsBatteryStatus>,
 }
 
+impl RobotStatusDdsMsg {
+    /// Decode the raw device gateway sample into a typed health view.
+    #[must_use]
+    pub fn decode(&self) -> DeviceStatus {
+        DeviceStatus {
+            joints: self
+                .joint_vec
+                .iter()
+                .map(|joint| JointHealth {
+                    name: joint.name.clone(),
+                    index: joint.index,
+                    connected: joint.is_connected,
+                    limited: joint.is_limited,
+                    temperature_c: joint.temperature,
+                    temperature_level: joint.temperature_level,
+                    error_code: nonzero(joint.status_code),
+                })
+                .collect(),
+            imus: self
+                .imu_vec
+                .iter()
+                .map(|imu| ImuHealth {
+                    name: imu.name.clone(),
+                    index: imu.index,
+                    connected: imu.is_connected,
+                    error_code: nonzero(imu.status_code),
+                })
+                .collect(),
+            batteries: self
+                .battery_vec
+                .iter()
+                .map(|battery| BatteryHealth {
+                    name: battery.name.clone(),
+                    index: battery.index,
+                    soc: battery.soc,
+                    soc_level: battery.soc_level,
+                    error_code: nonzero(battery.status_code),
+                })
+                .collect(),
+        }
+    }
+}
+
+fn nonzero(status_code: i32) -> Option<i32> {
+    (status_code != 0).then_some(status_code)
+}
+
+/// Typed view of a device gateway sample.
+///
+/// The gateway only reports per-device health. Robot mode is published
+/// separately on the motion state topic.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceStatus {
+    pub joints: Vec<JointHealth>,
+    pub imus: Vec<ImuHealth>,
+    pub batteries: Vec<BatteryHealth>,
+}
+
+impl DeviceStatus {
+    /// True when every device is connected and reports no error code.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.joints.iter().all(JointHealth::is_ok)
+            && self.imus.iter().all(ImuHealth::is_ok)
+            && self
+                .batteries
+                .iter()
+                .all(|battery| battery.error_code.is_none())
+    }
+
+    /// Joints that are disconnected or report an error code.
+    pub fn faulty_joints(&self) -> impl Iterator<Item = &JointHealth> {
+        self.joints.iter().filter(|joint| !joint.is_ok())
+    }
+}
+
+/// Health of a single joint actuator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JointHealth {
+    pub name: String,
+    pub index: i32,
+    pub connected: bool,
+    /// Whether the joint is currently at a position or torque limit.
+    pub limited: bool,
+    pub temperature_c: i32,
+    pub temperature_level: i32,
+    /// Device status code, `None` when the joint reports no error.
+    pub error_code: Option<i32>,
+}
+
+impl JointHealth {
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.connected && self.error_code.is_none()
+    }
+}
+
+/// Health of a single IMU.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImuHealth {
+    pub name: String,
+    pub index: i32,
+    pub connected: bool,
+    /// Device status code, `None` when the IMU reports no error.
+    pub error_code: Option<i32>,
+}
+
+impl ImuHealth {
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.connected && self.error_code.is_none()
+    }
+}
+
+/// Health of a single battery pack.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatteryHealth {
+    pub name: String,
+    pub index: i32,
+    pub soc: f32,
+    pub soc_level: i32,
+    /// Device status code, `None` when the battery reports no error.
+    pub error_code: Option<i32>,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct MotionState {
     pub current_mode: i32,
@@ -155,3 +285,40 @@ pub struct SafeMode {
     /// Raw payload for safe mode (schema not documented in DDS reference).
     pub data: Vec<u8>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn joint(index: i32, is_connected: bool, status_code: i32) -> RobotDdsJointStatus {
+        RobotDdsJointStatus {
+            name: format!("joint_{index}"),
+            index,
+            is_connected,
+            temperature: 40,
+            is_limited: false,
+            status_code,
+            temperature_level: 0,
+        }
+    }
+
+    #[test]
+    fn decode_flags_faulty_devices() {
+        let msg = RobotStatusDdsMsg {
+            joint_vec: vec![joint(0, true, 0), joint(1, false, 0), joint(2, true, 7)],
+            imu_vec: vec![RobotDdsImuStatus {
+                name: "imu".to_owned(),
+                index: 0,
+                is_connected: true,
+                status_code: 0,
+            }],
+            battery_vec: Vec::new(),
+        };
+
+        let status = msg.decode();
+        assert!(!status.is_healthy());
+        assert_eq!(status.joints[2].error_code, Some(7));
+        let faulty: Vec<i32> = status.faulty_joints().map(|joint| joint.index).collect();
+        assert_eq!(faulty, vec![1, 2]);
+    }
+}
@@ -1,20 +1,60 @@
 //! DDS runtime helpers for creating publishers and subscriptions.
 
 use serde::{Serialize, de::DeserializeOwned};
+use std::fmt::Display;
 use std::net::{IpAddr, Ipv4Addr};
+use std::sync::{Arc, Mutex, PoisonError, Weak};
+use std::time::Duration;
 use tokio::sync::mpsc;
 
 use rustdds::{
     DomainParticipant, DomainParticipantBuilder, Publisher, QosPolicyBuilder, Subscriber,
 };
 
-use crate::types::{DdsError, Result};
+use crate::types::{BoosterError, DdsError, Result};
 
 use super::topics::TopicSpec;
 
+/// Consecutive failed reads after which a subscription's reader thread warns.
+///
+/// An occasional undecodable sample only costs a single failed read; a long
+/// streak usually means the participant behind the reader is in trouble.
+const READ_ERRORS_BEFORE_WARNING: u32 = 50;
+
+/// Longest pause between retries of a failing reader.
+const MAX_READ_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Pause after the `errors`-th failed read in a row, doubling from 10 ms up to
+/// [`MAX_READ_RETRY_DELAY`].
+fn read_retry_delay(errors: u32) -> Duration {
+    let doublings = errors.saturating_sub(1).min(8);
+    (Duration::from_millis(10) * (1 << doublings)).min(MAX_READ_RETRY_DELAY)
+}
+
+/// Settings for a [`DdsNode`].
+///
+/// Build it from `DdsConfig::default()` with the `with_*` methods, or as a
+/// struct literal ending in `..Default::default()`.
 #[derive(Default, Debug, Clone)]
 pub struct DdsConfig {
     pub domain_id: u16,
+    /// Namespace inserted into every topic name, e.g. `robot1` turns
+    /// `rt/motion_state` into `rt/robot1/motion_state`. Empty by default.
+    pub topic_prefix: String,
+}
+
+impl DdsConfig {
+    #[must_use]
+    pub fn with_domain_id(mut self, domain_id: u16) -> Self {
+        self.domain_id = domain_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_topic_prefix(mut self, topic_prefix: impl Into<String>) -> Self {
+        self.topic_prefix = topic_prefix.into();
+        self
+    }
 }
 
 #[derive(Clone)]
@@ -22,6 +62,7 @@ pub struct DdsNode {
     participant: DomainParticipant,
     publisher: Publisher,
     subscriber: Subscriber,
+    topic_prefix: String,
 }
 
 impl DdsNode {
@@ -43,13 +84,20 @@ impl DdsNode {
             participant,
             publisher,
             subscriber,
+            topic_prefix: config.topic_prefix,
         })
     }
 
+    /// Namespace applied to every topic created through this node.
+    pub fn topic_prefix(&self) -> &str {
+        &self.topic_prefix
+    }
+
     pub fn publisher<T>(&self, spec: &TopicSpec) -> Result<DdsPublisher<T>>
     where
         T: Serialize,
     {
+        let spec = &spec.with_prefix(&self.topic_prefix);
         let topic = spec.create_topic(&self.participant)?;
         let writer = self
             .publisher
@@ -65,6 +113,7 @@ impl DdsNode {
     where
         T: DeserializeOwned + 'static,
     {
+        let spec = &spec.with_prefix(&self.topic_prefix);
         let topic = spec.create_topic(&self.participant)?;
         self.subscriber
             .create_datareader_no_key_cdr::<T>(&topic, Some(spec.qos.clone()))
@@ -75,10 +124,18 @@ impl DdsNode {
             .map_err(Into::into)
     }
 
+    /// Subscribe to a topic, delivering samples through a bounded channel.
+    ///
+    /// Every subscription gets a dedicated reader thread, so a slow consumer
+    /// never delays other subscriptions or RPC replies, which are read
+    /// through a separate async stream. When the consumer falls `buffer`
+    /// samples behind, newer samples wait in the DDS reader according to the
+    /// topic's QoS.
     pub fn subscribe<T>(&self, spec: &TopicSpec, buffer: usize) -> Result<DdsSubscription<T>>
     where
         T: DeserializeOwned + Send + 'static,
     {
+        let spec = &spec.with_prefix(&self.topic_prefix);
         let topic = spec.create_topic(&self.participant)?;
         let reader = self
             .subscriber
@@ -88,24 +145,82 @@ impl DdsNode {
                 reason: err.to_string(),
             })?;
 
-        let (sender, receiver) = mpsc::channel(buffer);
-        std::thread::spawn(move || {
-            let mut reader = reader;
-            loop {
-                match reader.take_next_sample() {
-                    Ok(Some(sample)) => {
-                        if sender.blocking_send(sample.into_value()).is_err() {
+        let mut reader = reader;
+        spawn_reader(spec.name.to_string(), buffer, move || {
+            reader
+                .take_next_sample()
+                .map(|sample| sample.map(rustdds::no_key::DataSample::into_value))
+        })
+        .map_err(|err| {
+            DdsError::SubscriberCreationFailed {
+                topic: spec.name.to_string(),
+                reason: err.to_string(),
+            }
+            .into()
+        })
+    }
+}
+
+/// Start the thread that moves samples from `take` into a new subscription.
+///
+/// Each subscription drains its reader on its own thread. A consumer that
+/// stops calling `recv` only blocks this thread once the channel fills;
+/// other subscriptions and RPC replies keep flowing. Failed reads are retried
+/// with a growing delay (see [`read_retry_delay`]), and a streak of
+/// [`READ_ERRORS_BEFORE_WARNING`] failures is logged with the last error. The
+/// thread exits within one poll or retry interval of the subscription being
+/// dropped, even if no further samples arrive.
+pub(super) fn spawn_reader<T, E>(
+    topic: String,
+    buffer: usize,
+    mut take: impl FnMut() -> std::result::Result<Option<T>, E> + Send + 'static,
+) -> std::io::Result<DdsSubscription<T>>
+where
+    T: Send + 'static,
+    E: Display,
+{
+    let (sender, receiver) = mpsc::channel(buffer);
+    std::thread::Builder::new()
+        .name(format!("dds-sub {topic}"))
+        .spawn(move || {
+            let mut errors = 0;
+            while !sender.is_closed() {
+                let sample = match take() {
+                    Ok(sample) => sample,
+                    Err(err) => {
+                        errors += 1;
+                        if errors == READ_ERRORS_BEFORE_WARNING {
+                            tracing::warn!(
+                                target: "booster_sdk::dds",
+                                topic = %topic,
+                                consecutive_errors = errors,
+                                error = %err,
+                                "subscription reader keeps failing; retrying with backoff"
+                            );
+                        }
+                        std::thread::sleep(read_retry_delay(errors));
+                        continue;
+                    }
+                };
+                if errors >= READ_ERRORS_BEFORE_WARNING {
+                    tracing::info!(
+                        target: "booster_sdk::dds",
+                        topic = %topic,
+                        "subscription reader recovered"
+                    );
+                }
+                errors = 0;
+                match sample {
+                    Some(sample) => {
+                        if sender.blocking_send(sample).is_err() {
                             break;
                         }
                     }
-                    Ok(None) => std::thread::sleep(std::time::Duration::from_millis(5)),
-                    Err(_) => std::thread::sleep(std::time::Duration::from_millis(10)),
+                    None => std::thread::sleep(Duration::from_millis(5)),
                 }
             }
-        });
-
-        Ok(DdsSubscription { receiver })
-    }
+        })?;
+    Ok(DdsSubscription { receiver })
 }
 
 pub struct DdsPublisher<T: Serialize> {
@@ -127,12 +242,326 @@ where
     }
 }
 
+/// Stream of samples from a single topic.
+///
+/// A subscription is bound to the participant of the [`DdsNode`] that created
+/// it and is never re-bound. When a client is recreated, for example after a
+/// robot reboot, recreate its subscriptions from the new client as well.
 pub struct DdsSubscription<T> {
     receiver: mpsc::Receiver<T>,
 }
 
 impl<T> DdsSubscription<T> {
+    /// Wait for the next sample, or `None` once the reader thread has stopped.
     pub async fn recv(&mut self) -> Option<T> {
         self.receiver.recv().await
     }
+
+    /// Wait for the next sample, failing if the subscription has closed.
+    ///
+    /// Convenient for one-shot reads such as the next battery reading.
+    pub async fn next(&mut self) -> Result<T> {
+        self.receiver
+            .recv()
+            .await
+            .ok_or_else(|| DdsError::ReceiveFailed("subscription closed".to_owned()).into())
+    }
+
+    /// Like [`Self::next`], failing with [`BoosterError::Timeout`] after `timeout`.
+    pub async fn next_with_timeout(&mut self, timeout: Duration) -> Result<T> {
+        self.wait_for(|_| true, timeout).await
+    }
+
+    /// Wait for the first sample matching `predicate`, discarding the others.
+    ///
+    /// Fails with [`BoosterError::Timeout`] if no sample matches within `timeout`.
+    pub async fn wait_for(
+        &mut self,
+        mut predicate: impl FnMut(&T) -> bool,
+        timeout: Duration,
+    ) -> Result<T> {
+        let wait = async {
+            while let Some(sample) = self.receiver.recv().await {
+                if predicate(&sample) {
+                    return Ok(sample);
+                }
+            }
+            Err(DdsError::ReceiveFailed("subscription closed".to_owned()).into())
+        };
+        tokio::time::timeout(timeout, wait)
+            .await
+            .unwrap_or_else(|_| Err(BoosterError::timeout(timeout)))
+    }
+
+    /// Whether the background reader feeding this subscription is still running.
+    ///
+    /// Read errors do not stop the reader: it retries with backoff and logs a
+    /// warning when they persist, so this stays `true` while the participant
+    /// is unreachable. It turns `false` only if the reader thread dies, e.g.
+    /// by panicking. Samples already buffered can still be received after
+    /// that; [`Self::next`] fails once they are drained.
+    pub fn is_alive(&self) -> bool {
+        !self.receiver.is_closed()
+    }
+}
+
+impl<T> DdsSubscription<T>
+where
+    T: Clone + Send + 'static,
+{
+    /// Keep only the newest sample, updated from a background thread.
+    ///
+    /// The thread stops on the first sample after every [`LatestSample`]
+    /// handle has been dropped, or when the subscription closes.
+    pub fn into_latest(self) -> Result<LatestSample<T>> {
+        let latest = Arc::new(Mutex::new(None));
+        let weak: Weak<Mutex<Option<T>>> = Arc::downgrade(&latest);
+        let mut receiver = self.receiver;
+        std::thread::Builder::new()
+            .name("dds-latest".to_owned())
+            .spawn(move || {
+                while let Some(sample) = receiver.blocking_recv() {
+                    let Some(latest) = weak.upgrade() else {
+                        break;
+                    };
+                    *latest.lock().unwrap_or_else(PoisonError::into_inner) = Some(sample);
+                }
+            })
+            .map_err(|err| DdsError::ReceiveFailed(err.to_string()))?;
+        Ok(LatestSample { latest })
+    }
+}
+
+/// Shared view of the newest sample of a subscription.
+///
+/// Cheap to clone; all clones observe the same sample.
+#[derive(Debug)]
+pub struct LatestSample<T> {
+    latest: Arc<Mutex<Option<T>>>,
+}
+
+impl<T> Clone for LatestSample<T> {
+    fn clone(&self) -> Self {
+        Self {
+            latest: Arc::clone(&self.latest),
+        }
+    }
+}
+
+impl<T: Clone> LatestSample<T> {
+    /// Newest sample received so far, or `None` before the first one.
+    pub fn get(&self) -> Option<T> {
+        self.latest
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+    use crate::dds::{
+        BatteryState, MotionState, RpcClient, RpcClientOptions, RpcReqMsg, RpcRespMsg,
+        battery_state_topic, motion_state_topic, rpc_request_topic, rpc_response_topic,
+    };
+
+    #[tokio::test]
+    async fn next_returns_samples_then_reports_closed() {
+        let (sender, receiver) = mpsc::channel(4);
+        let mut subscription = DdsSubscription { receiver };
+        sender.send(7_u32).await.unwrap();
+        assert_eq!(subscription.next().await.unwrap(), 7);
+
+        let timeout = subscription
+            .next_with_timeout(Duration::from_millis(10))
+            .await;
+        assert!(matches!(timeout, Err(BoosterError::Timeout { .. })));
+
+        drop(sender);
+        assert!(subscription.next().await.is_err());
+    }
+
+    #[test]
+    fn latest_sample_tracks_newest_value() {
+        let (sender, receiver) = mpsc::channel(4);
+        let latest = DdsSubscription { receiver }.into_latest().unwrap();
+        assert_eq!(latest.get(), None::<u32>);
+
+        for value in 1..=3 {
+            sender.blocking_send(value).unwrap();
+        }
+        let deadline = Instant::now() + Duration::from_secs(1);
+        while latest.get() != Some(3) && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert_eq!(latest.clone().get(), Some(3));
+    }
+
+    #[tokio::test]
+    async fn failing_reader_retries_until_reads_recover() {
+        let mut samples = vec![
+            Ok(Some(1_u32)),
+            Err("participant gone"),
+            Err("participant gone"),
+            Err("participant gone"),
+            Ok(None),
+            Ok(Some(2)),
+        ]
+        .into_iter();
+        let mut subscription = spawn_reader("test".to_owned(), 4, move || {
+            samples.next().unwrap_or(Ok(None))
+        })
+        .unwrap();
+
+        assert_eq!(subscription.next().await.unwrap(), 1);
+        assert_eq!(subscription.next().await.unwrap(), 2);
+        assert!(subscription.is_alive());
+    }
+
+    #[test]
+    fn read_retry_delay_doubles_up_to_the_cap() {
+        assert_eq!(read_retry_delay(1), Duration::from_millis(10));
+        assert_eq!(read_retry_delay(2), Duration::from_millis(20));
+        assert_eq!(read_retry_delay(4), Duration::from_millis(80));
+        assert_eq!(
+            read_retry_delay(READ_ERRORS_BEFORE_WARNING),
+            MAX_READ_RETRY_DELAY
+        );
+    }
+
+    #[tokio::test]
+    async fn stalled_subscription_does_not_delay_others() {
+        // Never drained: its reader thread blocks once the channel is full.
+        let _stalled = spawn_reader("stalled".to_owned(), 1, || {
+            Ok::<_, &str>(Some(MotionState {
+                current_mode: 2,
+                target_mode: 2,
+                is_transitioning: false,
+            }))
+        })
+        .unwrap();
+
+        let mut next = 0_u32;
+        let mut live = spawn_reader("live".to_owned(), 8, move || {
+            next += 1;
+            Ok::<_, &str>(Some(next))
+        })
+        .unwrap();
+
+        for expected in 1..=50 {
+            let sample = live
+                .next_with_timeout(Duration::from_millis(100))
+                .await
+                .unwrap();
+            assert_eq!(sample, expected);
+        }
+    }
+
+    #[test]
+    fn dropped_subscription_stops_an_idle_reader() {
+        // The reader closure owns `alive`; the thread dropping it means it exited.
+        let (alive, exited) = std::sync::mpsc::channel::<()>();
+        let subscription = spawn_reader("idle".to_owned(), 4, move || {
+            let _alive = &alive;
+            Ok::<Option<u32>, &str>(None)
+        })
+        .unwrap();
+
+        drop(subscription);
+        assert_eq!(
+            exited.recv_timeout(Duration::from_secs(1)),
+            Err(std::sync::mpsc::RecvTimeoutError::Disconnected)
+        );
+    }
+
+    const STRESS_DOMAIN: u16 = 77;
+    const STRESS_SERVICE: &str = "rt/StressTestApiTopic";
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[ignore = "requires a working DDS loopback network"]
+    async fn slow_subscriber_does_not_starve_other_readers() {
+        let config = DdsConfig::default().with_domain_id(STRESS_DOMAIN);
+        let node = DdsNode::new(config.clone()).expect("node");
+
+        // Echo server for the RPC path.
+        let server = DdsNode::new(config).expect("server node");
+        let mut requests = server
+            .subscribe::<RpcReqMsg>(&rpc_request_topic(STRESS_SERVICE), 16)
+            .expect("request subscription");
+        let responses = server
+            .publisher::<RpcRespMsg>(&rpc_response_topic(STRESS_SERVICE))
+            .expect("response publisher");
+        tokio::spawn(async move {
+            while let Some(request) = requests.recv().await {
+                let _ = responses.write(RpcRespMsg {
+                    uuid: request.uuid,
+                    header: r#"{"status":0}"#.to_owned(),
+                    body: "{}".to_owned(),
+                });
+            }
+        });
+
+        // Flood a topic whose subscriber never drains it.
+        let _stalled = node
+            .subscribe::<MotionState>(&motion_state_topic(), 1)
+            .expect("stalled subscription");
+        let flood = node
+            .publisher::<MotionState>(&motion_state_topic())
+            .expect("flood publisher");
+        std::thread::spawn(move || {
+            loop {
+                let state = MotionState {
+                    current_mode: 2,
+                    target_mode: 2,
+                    is_transitioning: false,
+                };
+                if flood.write(state).is_err() {
+                    break;
+                }
+                std::thread::sleep(Duration::from_micros(200));
+            }
+        });
+
+        let mut battery = node
+            .subscribe::<BatteryState>(&battery_state_topic(), 8)
+            .expect("battery subscription");
+        let battery_publisher = node
+            .publisher::<BatteryState>(&battery_state_topic())
+            .expect("battery publisher");
+
+        let client = RpcClient::new(
+            RpcClientOptions::for_service(STRESS_SERVICE)
+                .with_domain_id(STRESS_DOMAIN)
+                .with_startup_wait(Duration::from_secs(1))
+                .with_default_timeout(Duration::from_secs(2)),
+        )
+        .expect("rpc client");
+
+        for _ in 0..50 {
+            let started = Instant::now();
+            client
+                .call_raw(0, "{}", None)
+                .await
+                .expect("rpc reply while topic is flooded");
+            assert!(started.elapsed() < Duration::from_secs(2));
+
+            battery_publisher
+                .write(BatteryState {
+                    voltage: 48.0,
+                    current: 1.0,
+                    temperature: 30.0,
+                    soc: 80.0,
+                    health: 0,
+                    status_code: 0,
+                })
+                .expect("battery write");
+            tokio::time::timeout(Duration::from_secs(1), battery.recv())
+                .await
+                .expect("battery sample while topic is flooded")
+                .expect("battery subscription open");
+        }
+    }
 }
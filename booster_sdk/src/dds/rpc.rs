@@ -1,26 +1,99 @@
 //! RPC client for high-level API requests over DDS.
 
-use futures::StreamExt;
-use rustdds::no_key::DataReaderStream;
+use futures::{Stream, StreamExt};
+use rustdds::no_key::{DataReaderStream, DataSample};
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use serde_json::Value;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
 use uuid::Uuid;
 
-use crate::types::{DdsError, Result, RpcError};
+use crate::types::{BoosterError, DdsError, Result, RpcError};
 
 use super::DdsNode;
 use super::messages::{RpcReqMsg, RpcRespMsg};
 use super::topics::{LOCO_API_TOPIC, rpc_request_topic, rpc_response_topic};
 
+tokio::task_local! {
+    static CALL_TIMEOUT: Duration;
+}
+
+/// Run `future` with every RPC call made inside it using `timeout`.
+///
+/// The override replaces the client's default timeout, so callers can tune a
+/// single high-level operation without reconstructing the client. Methods
+/// that need a longer minimum, such as `change_mode`, keep theirs if it
+/// exceeds `timeout`.
+///
+/// The override is task-local: it only covers RPCs awaited on the current
+/// task, not work spawned onto other tasks.
+pub async fn with_call_timeout<F>(timeout: Duration, future: F) -> F::Output
+where
+    F: Future,
+{
+    CALL_TIMEOUT.scope(timeout, future).await
+}
+
+/// Default upper bound on the size of an RPC response body.
+const DEFAULT_MAX_RESPONSE_BYTES: usize = 4 * 1024 * 1024;
+
+/// Default number of timed-out request ids remembered for late-reply accounting.
+const DEFAULT_LATE_REPLY_WINDOW: usize = 16;
+
+/// Consecutive timeouts of one api id before warning that it may be unsupported.
+const UNSUPPORTED_API_TIMEOUTS: u32 = 3;
+
+/// Extra attempts for read-only queries that time out before any reply was seen.
+const DISCOVERY_RETRIES: usize = 2;
+
+/// Timeout of each attempt that may still be retried by [`RpcClient::call_query`].
+const DISCOVERY_ATTEMPT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Point from which [`RpcClientOptions::startup_wait`] is measured.
+///
+/// Discovery runs in the DDS participant from construction either way; this
+/// only decides how much of the wait the first call still sleeps. Nothing is
+/// read or dispatched in the background before that call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StartupWaitStart {
+    /// The first call sleeps the full startup wait.
+    #[default]
+    FirstCall,
+    /// The first call only sleeps whatever part of the wait has not already
+    /// elapsed since the client was constructed.
+    Construction,
+}
+
+/// Settings for an [`RpcClient`].
+///
+/// Build it from `RpcClientOptions::default()` or [`Self::for_service`] with
+/// the `with_*` methods, or as a struct literal ending in
+/// `..Default::default()`.
 #[derive(Debug)]
 pub struct RpcClientOptions {
     pub domain_id: u16,
     pub default_timeout: Duration,
     pub startup_wait: Duration,
     pub service_topic: String,
+    /// Robot namespace for all topics; see [`super::DdsConfig::topic_prefix`].
+    pub topic_prefix: String,
+    /// Replies whose header or body exceed this many bytes fail the call.
+    ///
+    /// The check runs after the sample has been decoded, so it guards
+    /// against acting on runaway replies, not against allocating them.
+    pub max_response_bytes: usize,
+    /// How many timed-out request ids are remembered so replies arriving
+    /// after their deadline are counted as late rather than orphaned.
+    pub late_reply_window: usize,
+    /// When the startup wait starts counting; see [`StartupWaitStart`].
+    pub startup_wait_start: StartupWaitStart,
+    /// Make `BoosterClient` refuse motion, hand and gripper commands while
+    /// the robot is in damping mode, instead of sending them to be ignored.
+    /// Costs a mode lookup per command unless the motion state cache is running.
+    pub guard_mode: bool,
 }
 
 impl Default for RpcClientOptions {
@@ -28,11 +101,16 @@ impl Default for RpcClientOptions {
         Self {
             domain_id: 0,
             // 5 s is a safe default for most commands. Mode changes are slow,
-            // so change_mode passes its own longer timeout.
+            // so BoosterClient::change_mode passes its own longer timeout.
             default_timeout: Duration::from_secs(5),
             // Wait once before the first RPC call so endpoint discovery can settle.
             startup_wait: Duration::from_millis(3000),
             service_topic: LOCO_API_TOPIC.to_owned(),
+            topic_prefix: String::new(),
+            max_response_bytes: DEFAULT_MAX_RESPONSE_BYTES,
+            late_reply_window: DEFAULT_LATE_REPLY_WINDOW,
+            startup_wait_start: StartupWaitStart::FirstCall,
+            guard_mode: false,
         }
     }
 }
@@ -46,12 +124,24 @@ impl RpcClientOptions {
         }
     }
 
+    #[must_use]
+    pub fn with_domain_id(mut self, domain_id: u16) -> Self {
+        self.domain_id = domain_id;
+        self
+    }
+
     #[must_use]
     pub fn with_service_topic(mut self, service_topic: impl Into<String>) -> Self {
         self.service_topic = service_topic.into();
         self
     }
 
+    #[must_use]
+    pub fn with_topic_prefix(mut self, topic_prefix: impl Into<String>) -> Self {
+        self.topic_prefix = topic_prefix.into();
+        self
+    }
+
     #[must_use]
     pub fn with_default_timeout(mut self, timeout: Duration) -> Self {
         self.default_timeout = timeout;
@@ -68,20 +158,55 @@ impl RpcClientOptions {
     pub fn without_startup_wait(self) -> Self {
         self.with_startup_wait(Duration::from_millis(0))
     }
+
+    #[must_use]
+    pub fn with_max_response_bytes(mut self, max_response_bytes: usize) -> Self {
+        self.max_response_bytes = max_response_bytes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_startup_wait_start(mut self, start: StartupWaitStart) -> Self {
+        self.startup_wait_start = start;
+        self
+    }
+
+    #[must_use]
+    pub fn with_guard_mode(mut self, guard_mode: bool) -> Self {
+        self.guard_mode = guard_mode;
+        self
+    }
+
+    #[must_use]
+    pub fn with_late_reply_window(mut self, late_reply_window: usize) -> Self {
+        self.late_reply_window = late_reply_window;
+        self
+    }
 }
 
 pub struct RpcClient {
     node: DdsNode,
     request_writer: rustdds::no_key::DataWriter<RpcReqMsg>,
     response_stream: Mutex<DataReaderStream<RpcRespMsg>>,
-    default_timeout: Duration,
+    /// Default timeout in nanoseconds; see [`RpcClient::set_default_timeout`].
+    default_timeout_nanos: AtomicU64,
     startup_wait: Duration,
     startup_wait_done: AtomicBool,
+    /// Set when the startup wait starts at [`StartupWaitStart::Construction`].
+    created_at: Option<Instant>,
+    response_seen: AtomicBool,
+    max_response_bytes: usize,
+    unmatched_replies: UnmatchedReplies,
+    timeout_streaks: TimeoutStreaks,
     service_topic: String,
 }
 
-#[derive(Debug, Deserialize, Default)]
-struct EmptyResponse {}
+/// Response type for calls that return no payload.
+///
+/// Empty bodies decode as `{}` and unknown fields are ignored, so every
+/// client's void calls behave the same regardless of what the service echoes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+pub struct VoidResponse {}
 
 fn parse_status_value(value: &Value) -> Option<i32> {
     match value {
@@ -97,6 +222,192 @@ fn parse_status_from_header(raw_json: &str) -> Option<i32> {
     object.get("status").and_then(parse_status_value)
 }
 
+/// Serialize request parameters into a compact JSON body.
+///
+/// Parameterless calls pass `&()`, which is sent as `{}` so every request
+/// body is a JSON object. Everything else is serialized directly, keeping
+/// field order and `f32` formatting exactly as the type defines them.
+pub fn serialize_param<P>(params: &P) -> Result<String>
+where
+    P: Serialize + ?Sized,
+{
+    let body = serde_json::to_string(params)?;
+    if body == "null" {
+        return Ok("{}".to_owned());
+    }
+    Ok(body)
+}
+
+/// Accounting for replies that do not belong to the call reading them.
+///
+/// Calls on one client are serialized, so a reply for a timed-out request is
+/// only read, and counted, by the next call on the same client.
+#[derive(Debug)]
+struct UnmatchedReplies {
+    window: usize,
+    timed_out: std::sync::Mutex<VecDeque<String>>,
+    late: AtomicU64,
+    orphaned: AtomicU64,
+}
+
+impl UnmatchedReplies {
+    fn new(window: usize) -> Self {
+        Self {
+            window,
+            timed_out: std::sync::Mutex::new(VecDeque::with_capacity(window)),
+            late: AtomicU64::new(0),
+            orphaned: AtomicU64::new(0),
+        }
+    }
+
+    fn record_timeout(&self, request_id: &str) {
+        if self.window == 0 {
+            return;
+        }
+        let mut timed_out = self
+            .timed_out
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if timed_out.len() == self.window {
+            timed_out.pop_front();
+        }
+        timed_out.push_back(request_id.to_owned());
+    }
+
+    /// Count a reply for `uuid`, returning whether it belongs to a timed-out call.
+    fn record_reply(&self, uuid: &str) -> bool {
+        let late = self
+            .timed_out
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .iter()
+            .any(|request_id| request_id == uuid);
+        let counter = if late { &self.late } else { &self.orphaned };
+        counter.fetch_add(1, Ordering::Relaxed);
+        late
+    }
+}
+
+/// Request header for `api_id`, always `{"api_id":<integer>}`.
+///
+/// The robot matches the id numerically; a string id such as `"2000"` is
+/// silently ignored and the call times out.
+fn request_header(api_id: i32) -> String {
+    serde_json::json!({ "api_id": api_id }).to_string()
+}
+
+/// `duration` in nanoseconds, saturating at roughly 584 years.
+fn duration_nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// Timeout of one call given its own `timeout` and a [`with_call_timeout`] override.
+///
+/// The override replaces `default` but never shortens a timeout the call
+/// passes itself.
+fn resolve_call_timeout(
+    timeout: Option<Duration>,
+    scoped: Option<Duration>,
+    default: Duration,
+) -> Duration {
+    match (timeout, scoped) {
+        (Some(timeout), Some(scoped)) => timeout.max(scoped),
+        (Some(timeout), None) | (None, Some(timeout)) => timeout,
+        (None, None) => default,
+    }
+}
+
+/// Part of `startup_wait` the first call still has to sleep.
+///
+/// `since_construction` is `Some` when the wait is counted from client
+/// construction; time already spent there is subtracted.
+fn remaining_startup_wait(
+    startup_wait: Duration,
+    since_construction: Option<Duration>,
+) -> Duration {
+    since_construction.map_or(startup_wait, |elapsed| startup_wait.saturating_sub(elapsed))
+}
+
+/// Consecutive timeouts per api id, reset whenever the api id gets a reply.
+#[derive(Debug, Default)]
+struct TimeoutStreaks(std::sync::Mutex<HashMap<i32, u32>>);
+
+impl TimeoutStreaks {
+    /// Count a timeout, returning `true` exactly when the streak reaches
+    /// [`UNSUPPORTED_API_TIMEOUTS`].
+    fn record_timeout(&self, api_id: i32) -> bool {
+        let mut streaks = self
+            .0
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let streak = streaks.entry(api_id).or_insert(0);
+        *streak = streak.saturating_add(1);
+        *streak == UNSUPPORTED_API_TIMEOUTS
+    }
+
+    fn reset(&self, api_id: i32) {
+        self.0
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .remove(&api_id);
+    }
+}
+
+/// How a received reply relates to the request currently waiting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReplyKind {
+    /// Reply to another (usually earlier, timed out) request.
+    OtherRequest,
+    /// Progress reply with `status=-1`; keep waiting.
+    Intermediate,
+    /// Final reply carrying the call's outcome.
+    Final { status_code: i32 },
+}
+
+/// Classify a reply for `request_id`.
+///
+/// A call completes on the first final reply with a matching uuid. Any
+/// further replies for that uuid arrive after the call has returned and are
+/// dropped as [`ReplyKind::OtherRequest`] by later calls, since every call
+/// uses a fresh uuid.
+fn classify_reply(request_id: &str, response: &RpcRespMsg) -> ReplyKind {
+    if response.uuid != request_id {
+        return ReplyKind::OtherRequest;
+    }
+    match parse_status_from_header(&response.header).unwrap_or(0) {
+        -1 => ReplyKind::Intermediate,
+        status_code => ReplyKind::Final { status_code },
+    }
+}
+
+/// Reject replies whose header or body exceed `max_bytes`.
+fn check_response_size(
+    response: &RpcRespMsg,
+    max_bytes: usize,
+) -> std::result::Result<(), RpcError> {
+    let size = response.header.len().max(response.body.len());
+    if size > max_bytes {
+        return Err(RpcError::InternalServerError(format!(
+            "response of {size} bytes exceeds the {max_bytes} byte limit"
+        )));
+    }
+    Ok(())
+}
+
+/// Decode a successful reply body into `R`.
+fn decode_reply<R>(body: &str) -> Result<R>
+where
+    R: DeserializeOwned,
+{
+    decode_response_body(body).map_err(|err| {
+        RpcError::RequestFailed {
+            status: 0,
+            message: format!("Failed to deserialize response body: {err}"),
+        }
+        .into()
+    })
+}
+
 fn decode_response_body<R>(body: &str) -> std::result::Result<R, serde_json::Error>
 where
     R: DeserializeOwned,
@@ -123,7 +434,7 @@ fn normalize_service_topic(service_topic: &str) -> String {
     trimmed.to_owned()
 }
 
-fn preview_for_log(value: &str, max_chars: usize) -> String {
+pub(crate) fn preview_for_log(value: &str, max_chars: usize) -> String {
     let mut preview = String::new();
     let mut chars = value.chars();
     for _ in 0..max_chars {
@@ -144,9 +455,11 @@ impl RpcClient {
     }
 
     pub fn new(options: RpcClientOptions) -> Result<Self> {
-        let node = DdsNode::new(super::DdsConfig {
-            domain_id: options.domain_id,
-        })?;
+        let node = DdsNode::new(
+            super::DdsConfig::default()
+                .with_domain_id(options.domain_id)
+                .with_topic_prefix(options.topic_prefix.clone()),
+        )?;
 
         let service_topic = normalize_service_topic(&options.service_topic);
         let request_topic = rpc_request_topic(&service_topic);
@@ -160,9 +473,15 @@ impl RpcClient {
             node,
             request_writer: request_writer.into_inner(),
             response_stream: Mutex::new(response_stream),
-            default_timeout: options.default_timeout,
+            default_timeout_nanos: AtomicU64::new(duration_nanos(options.default_timeout)),
             startup_wait: options.startup_wait,
             startup_wait_done: AtomicBool::new(false),
+            created_at: (options.startup_wait_start == StartupWaitStart::Construction)
+                .then(Instant::now),
+            response_seen: AtomicBool::new(false),
+            max_response_bytes: options.max_response_bytes,
+            unmatched_replies: UnmatchedReplies::new(options.late_reply_window),
+            timeout_streaks: TimeoutStreaks::default(),
             service_topic,
         })
     }
@@ -171,6 +490,37 @@ impl RpcClient {
         &self.node
     }
 
+    /// Timeout used by calls that do not pass their own.
+    pub fn default_timeout(&self) -> Duration {
+        Duration::from_nanos(self.default_timeout_nanos.load(Ordering::Relaxed))
+    }
+
+    /// Change the default timeout for subsequent calls.
+    ///
+    /// Safe to call while other tasks share the client; calls already waiting
+    /// keep the timeout they started with. High-level clients expose this
+    /// through their `rpc()` accessor.
+    pub fn set_default_timeout(&self, timeout: Duration) {
+        self.default_timeout_nanos
+            .store(duration_nanos(timeout), Ordering::Relaxed);
+    }
+
+    /// Replies received for recently timed-out calls of this client.
+    ///
+    /// A growing count means the service answers, just slower than the
+    /// timeout allows. Only the last `late_reply_window` timeouts are tracked.
+    pub fn late_replies(&self) -> u64 {
+        self.unmatched_replies.late.load(Ordering::Relaxed)
+    }
+
+    /// Replies whose request id matches no recent call of this client.
+    ///
+    /// These are typically replies to other clients on the same service
+    /// topic, or to calls that timed out too long ago to be tracked.
+    pub fn orphaned_replies(&self) -> u64 {
+        self.unmatched_replies.orphaned.load(Ordering::Relaxed)
+    }
+
     pub async fn call_void<ApiId>(&self, api_id: ApiId, body: impl Into<String>) -> Result<()>
     where
         ApiId: Into<i32> + Copy,
@@ -187,7 +537,7 @@ impl RpcClient {
     where
         ApiId: Into<i32> + Copy,
     {
-        self.call_with_body::<EmptyResponse>(api_id.into(), body.into(), timeout)
+        self.call_with_body::<VoidResponse>(api_id.into(), body.into(), timeout)
             .await?;
         Ok(())
     }
@@ -200,12 +550,61 @@ impl RpcClient {
         self.call_with_body(api_id.into(), body.into(), None).await
     }
 
+    /// Call a read-only API, retrying timeouts that happen before any reply was ever seen.
+    ///
+    /// The first calls after construction can time out while DDS discovery is
+    /// still settling, so until any response has arrived the query is sent
+    /// in short attempts. All attempts together stay within the usual call
+    /// timeout. Only use this for idempotent queries.
+    pub async fn call_query<ApiId, P, R>(&self, api_id: ApiId, params: &P) -> Result<R>
+    where
+        ApiId: Into<i32> + Copy,
+        P: Serialize,
+        R: DeserializeOwned + Send + 'static,
+    {
+        let body = serialize_param(params)?;
+        self.wait_for_startup().await;
+        let timeout = self.call_timeout(None);
+        let deadline = Instant::now() + timeout;
+        let mut retries = 0;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let may_retry = retries < DISCOVERY_RETRIES
+                && !self.response_seen.load(Ordering::SeqCst)
+                && remaining > DISCOVERY_ATTEMPT_TIMEOUT;
+            let attempt_timeout = if may_retry {
+                DISCOVERY_ATTEMPT_TIMEOUT
+            } else {
+                remaining
+            };
+            match self
+                .send_request(api_id.into(), body.clone(), attempt_timeout, !may_retry)
+                .await
+            {
+                Err(BoosterError::Rpc(RpcError::Timeout { .. })) if may_retry => {
+                    retries += 1;
+                    tracing::debug!(
+                        target: "booster_sdk::rpc",
+                        service_topic = %self.service_topic,
+                        api_id = api_id.into(),
+                        attempt = retries,
+                        "no reply seen yet, retrying query after discovery timeout"
+                    );
+                }
+                Err(BoosterError::Rpc(RpcError::Timeout { .. })) => {
+                    return Err(RpcError::Timeout { timeout }.into());
+                }
+                result => return decode_reply(&result?),
+            }
+        }
+    }
+
     pub async fn call_serialized<ApiId, P>(&self, api_id: ApiId, params: &P) -> Result<()>
     where
         ApiId: Into<i32> + Copy,
         P: Serialize,
     {
-        self.call_void(api_id, serde_json::to_string(params)?).await
+        self.call_void(api_id, serialize_param(params)?).await
     }
 
     pub async fn call_serialized_response<ApiId, P, R>(
@@ -218,8 +617,7 @@ impl RpcClient {
         P: Serialize,
         R: DeserializeOwned + Send + 'static,
     {
-        self.call_response(api_id, serde_json::to_string(params)?)
-            .await
+        self.call_response(api_id, serialize_param(params)?).await
     }
 
     pub async fn call<P, R>(&self, api_id: i32, params: &P, timeout: Option<Duration>) -> Result<R>
@@ -227,7 +625,7 @@ impl RpcClient {
         P: Serialize,
         R: DeserializeOwned + Send + 'static,
     {
-        let body = serde_json::to_string(params).map_err(|e| {
+        let body = serialize_param(params).map_err(|e| {
             RpcError::BadRequest(format!("Failed to serialize request parameters: {e}"))
         })?;
 
@@ -243,24 +641,71 @@ impl RpcClient {
     where
         R: DeserializeOwned + Send + 'static,
     {
+        let body = self.call_raw(api_id, body, timeout).await?;
+        decode_reply(&body)
+    }
+
+    /// Send a request with a pre-serialized body and return the raw response body.
+    ///
+    /// Non-zero status codes are still mapped to [`RpcError`]; only the
+    /// success body is returned undecoded.
+    pub async fn call_raw(
+        &self,
+        api_id: i32,
+        body: impl Into<String>,
+        timeout: Option<Duration>,
+    ) -> Result<String> {
+        self.wait_for_startup().await;
+        let timeout = self.call_timeout(timeout);
+        self.send_request(api_id, body.into(), timeout, true).await
+    }
+
+    /// Timeout for a call that passes `timeout`, or `None` for the default.
+    fn call_timeout(&self, timeout: Option<Duration>) -> Duration {
+        resolve_call_timeout(
+            timeout,
+            CALL_TIMEOUT.try_with(|timeout| *timeout).ok(),
+            self.default_timeout(),
+        )
+    }
+
+    /// Sleep for the startup wait if this is the first call on the client.
+    async fn wait_for_startup(&self) {
         if self.startup_wait > Duration::from_millis(0)
             && !self.startup_wait_done.swap(true, Ordering::SeqCst)
         {
+            let wait = remaining_startup_wait(
+                self.startup_wait,
+                self.created_at.map(|created_at| created_at.elapsed()),
+            );
             tracing::debug!(
                 target: "booster_sdk::rpc",
                 service_topic = %self.service_topic,
                 startup_wait_ms = self.startup_wait.as_millis(),
+                remaining_ms = wait.as_millis(),
                 "initial startup wait before first rpc call"
             );
-            tokio::time::sleep(self.startup_wait).await;
+            tokio::time::sleep(wait).await;
         }
+    }
 
+    /// Send one request and wait up to `timeout` for its final reply.
+    ///
+    /// `final_attempt` is `false` for attempts the caller retries on timeout,
+    /// so that one logical call counts at most once towards the timeout
+    /// streak of its api id.
+    async fn send_request(
+        &self,
+        api_id: i32,
+        body: String,
+        timeout: Duration,
+        final_attempt: bool,
+    ) -> Result<String> {
         // Single-flight per client: one response stream consumer at a time.
         let mut response_stream = self.response_stream.lock().await;
 
         let request_id = Uuid::new_v4().to_string();
-        let body = body.into();
-        let header = serde_json::json!({ "api_id": api_id }).to_string();
+        let header = request_header(api_id);
         let service_topic = self.service_topic.clone();
 
         tracing::debug!(
@@ -283,105 +728,196 @@ impl RpcClient {
             .write(request, None)
             .map_err(|err| RpcError::BadRequest(format!("Failed to send request: {err}")))?;
 
-        let timeout = timeout.unwrap_or(self.default_timeout);
-        let deadline = Instant::now() + timeout;
+        let call = PendingCall {
+            service_topic: &service_topic,
+            api_id,
+            request_id: &request_id,
+            timeout,
+            final_attempt,
+            max_response_bytes: self.max_response_bytes,
+            response_seen: &self.response_seen,
+            unmatched_replies: &self.unmatched_replies,
+            timeout_streaks: &self.timeout_streaks,
+        };
+        let mut responses =
+            (&mut *response_stream).map(|sample| sample.map(DataSample::into_value));
+        call.receive(&mut responses).await
+    }
+}
+
+/// One in-flight request and the client state its receive loop updates.
+struct PendingCall<'a> {
+    service_topic: &'a str,
+    api_id: i32,
+    request_id: &'a str,
+    timeout: Duration,
+    /// Whether a timeout ends the logical call; retried attempts leave the
+    /// timeout streak alone.
+    final_attempt: bool,
+    max_response_bytes: usize,
+    response_seen: &'a AtomicBool,
+    unmatched_replies: &'a UnmatchedReplies,
+    timeout_streaks: &'a TimeoutStreaks,
+}
 
+impl PendingCall<'_> {
+    /// Read `responses` until the final reply for this request arrives.
+    ///
+    /// Replies for other requests and intermediate `status=-1` replies are
+    /// skipped; the first final reply decides the outcome.
+    async fn receive<S, E>(&self, responses: &mut S) -> Result<String>
+    where
+        S: Stream<Item = std::result::Result<RpcRespMsg, E>> + Unpin,
+        E: Display,
+    {
+        let deadline = Instant::now() + self.timeout;
         loop {
             let remaining = deadline.saturating_duration_since(Instant::now());
-            let response = match tokio::time::timeout(remaining, response_stream.next()).await {
-                Ok(Some(Ok(sample))) => sample.into_value(),
+            let response = match tokio::time::timeout(remaining, responses.next()).await {
+                Ok(Some(Ok(response))) => {
+                    self.response_seen.store(true, Ordering::SeqCst);
+                    response
+                }
                 Ok(Some(Err(err))) => {
+                    // A sample that fails to decode, e.g. because of invalid
+                    // UTF-8, cannot be matched to a request. Fail the call
+                    // rather than wait for a reply that may never decode.
                     tracing::warn!(
                         target: "booster_sdk::rpc",
-                        service_topic = %service_topic,
-                        api_id,
-                        request_uuid = %request_id,
+                        service_topic = %self.service_topic,
+                        api_id = self.api_id,
+                        request_uuid = %self.request_id,
                         error = %err,
-                        "rpc receive error"
+                        "undecodable rpc response"
                     );
-                    return Err(DdsError::ReceiveFailed(err.to_string()).into());
+                    return Err(RpcError::InternalServerError(format!(
+                        "Failed to decode rpc response: {err}"
+                    ))
+                    .into());
                 }
                 Ok(None) => {
                     return Err(
                         DdsError::ReceiveFailed("rpc response stream closed".to_owned()).into(),
                     );
                 }
-                Err(_) => {
-                    tracing::warn!(
+                Err(_) => return Err(self.timed_out()),
+            };
+
+            match classify_reply(self.request_id, &response) {
+                ReplyKind::OtherRequest => self.skip_other_request(&response),
+                ReplyKind::Intermediate => {
+                    self.accept(&response)?;
+                    tracing::debug!(
                         target: "booster_sdk::rpc",
-                        service_topic = %service_topic,
-                        api_id,
-                        request_uuid = %request_id,
-                        timeout_ms = timeout.as_millis(),
-                        "rpc timeout"
+                        service_topic = %self.service_topic,
+                        api_id = self.api_id,
+                        request_uuid = %self.request_id,
+                        "ignoring intermediate status=-1"
                     );
-                    return Err(RpcError::Timeout { timeout }.into());
                 }
-            };
-
-            if response.uuid != request_id {
-                tracing::debug!(
-                    target: "booster_sdk::rpc",
-                    service_topic = %service_topic,
-                    api_id,
-                    request_uuid = %request_id,
-                    response_uuid = %response.uuid,
-                    "ignoring response for a different request uuid"
-                );
-                continue;
+                ReplyKind::Final { status_code } => {
+                    self.accept(&response)?;
+                    self.timeout_streaks.reset(self.api_id);
+                    if status_code != 0 {
+                        let message = if response.body.trim().is_empty() {
+                            response.header
+                        } else {
+                            response.body
+                        };
+                        return Err(RpcError::from_status_code(status_code, message).into());
+                    }
+                    return Ok(response.body);
+                }
             }
+        }
+    }
 
-            let status_code = parse_status_from_header(&response.header).unwrap_or(0);
+    /// Check and log a reply that belongs to this request.
+    fn accept(&self, response: &RpcRespMsg) -> Result<()> {
+        check_response_size(response, self.max_response_bytes)?;
+        tracing::debug!(
+            target: "booster_sdk::rpc",
+            service_topic = %self.service_topic,
+            api_id = self.api_id,
+            request_uuid = %self.request_id,
+            header = %preview_for_log(&response.header, 200),
+            body = %preview_for_log(&response.body, 300),
+            "recv rpc response"
+        );
+        Ok(())
+    }
+
+    fn skip_other_request(&self, response: &RpcRespMsg) {
+        if self.unmatched_replies.record_reply(&response.uuid) {
+            tracing::warn!(
+                target: "booster_sdk::rpc",
+                service_topic = %self.service_topic,
+                api_id = self.api_id,
+                request_uuid = %self.request_id,
+                response_uuid = %response.uuid,
+                "ignoring late response for a timed-out request"
+            );
+        } else {
             tracing::debug!(
                 target: "booster_sdk::rpc",
-                service_topic = %service_topic,
-                api_id,
-                request_uuid = %request_id,
+                service_topic = %self.service_topic,
+                api_id = self.api_id,
+                request_uuid = %self.request_id,
                 response_uuid = %response.uuid,
-                status_code,
-                header = %preview_for_log(&response.header, 200),
-                body = %preview_for_log(&response.body, 300),
-                "recv rpc response"
+                "ignoring response for a different request uuid"
             );
+        }
+    }
 
-            if status_code == -1 {
-                tracing::debug!(
-                    target: "booster_sdk::rpc",
-                    service_topic = %service_topic,
-                    api_id,
-                    request_uuid = %request_id,
-                    "ignoring intermediate status=-1"
-                );
-                continue;
-            }
-
-            if status_code != 0 {
-                let message = if response.body.trim().is_empty() {
-                    response.header
-                } else {
-                    response.body
-                };
-                return Err(RpcError::from_status_code(status_code, message).into());
-            }
-
-            let result: R =
-                decode_response_body(&response.body).map_err(|err| RpcError::RequestFailed {
-                    status: status_code,
-                    message: format!("Failed to deserialize response body: {err}"),
-                })?;
-
-            return Ok(result);
+    fn timed_out(&self) -> BoosterError {
+        tracing::warn!(
+            target: "booster_sdk::rpc",
+            service_topic = %self.service_topic,
+            api_id = self.api_id,
+            request_uuid = %self.request_id,
+            timeout_ms = self.timeout.as_millis(),
+            "rpc timeout"
+        );
+        self.unmatched_replies.record_timeout(self.request_id);
+        if self.final_attempt && self.timeout_streaks.record_timeout(self.api_id) {
+            tracing::warn!(
+                target: "booster_sdk::rpc",
+                service_topic = %self.service_topic,
+                api_id = self.api_id,
+                consecutive_timeouts = UNSUPPORTED_API_TIMEOUTS,
+                "api id keeps timing out; the robot firmware may not support it"
+            );
+        }
+        RpcError::Timeout {
+            timeout: self.timeout,
         }
+        .into()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{decode_response_body, parse_status_from_header, parse_status_value};
+    use super::{
+        DISCOVERY_RETRIES, PendingCall, ReplyKind, RpcRespMsg, TimeoutStreaks,
+        UNSUPPORTED_API_TIMEOUTS, UnmatchedReplies, VoidResponse, check_response_size,
+        classify_reply, decode_response_body, parse_status_from_header, parse_status_value,
+        remaining_startup_wait, request_header, resolve_call_timeout, serialize_param,
+    };
+    use crate::dds::node::spawn_reader;
+    use crate::types::{BoosterError, Result, RpcError};
+    use futures::{StreamExt, stream};
     use serde_json::json;
+    use std::string::FromUtf8Error;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
 
-    #[derive(serde::Deserialize)]
-    struct EmptyResponse {}
+    #[test]
+    fn request_header_carries_integer_api_id() {
+        assert_eq!(request_header(2000), r#"{"api_id":2000}"#);
+        assert_eq!(request_header(-1), r#"{"api_id":-1}"#);
+        let parsed: serde_json::Value = serde_json::from_str(&request_header(3002)).unwrap();
+        assert!(parsed["api_id"].is_i64());
+    }
 
     #[test]
     fn parse_status_from_header_reads_status_field() {
@@ -404,12 +940,403 @@ mod tests {
 
     #[test]
     fn empty_body_deserializes_as_empty_object() {
-        let _: EmptyResponse = decode_response_body("").expect("empty body should parse");
+        let _: VoidResponse = decode_response_body("").expect("empty body should parse");
+    }
+
+    #[test]
+    fn void_response_accepts_whitespace_and_extra_fields() {
+        assert_eq!(
+            decode_response_body::<VoidResponse>("  \n").ok(),
+            Some(VoidResponse {})
+        );
+        assert_eq!(
+            decode_response_body::<VoidResponse>("{}").ok(),
+            Some(VoidResponse {})
+        );
+        assert_eq!(
+            decode_response_body::<VoidResponse>(r#"{"result":"ok"}"#).ok(),
+            Some(VoidResponse {})
+        );
+    }
+
+    #[test]
+    fn serialize_param_emits_empty_object_for_unit() {
+        assert_eq!(serialize_param(&()).unwrap(), "{}");
+        assert_eq!(serialize_param(&json!({})).unwrap(), "{}");
+    }
+
+    #[test]
+    fn serialize_param_is_compact() {
+        assert_eq!(
+            serialize_param(&json!({ "mode": 1, "name": "a b" })).unwrap(),
+            r#"{"mode":1,"name":"a b"}"#
+        );
+    }
+
+    #[test]
+    fn serialize_param_keeps_field_order_and_f32_text() {
+        #[derive(serde::Serialize)]
+        struct Params {
+            vy: f32,
+            vx: f32,
+            vyaw: f32,
+        }
+
+        assert_eq!(
+            serialize_param(&Params {
+                vy: 0.1,
+                vx: 0.3,
+                vyaw: -0.7,
+            })
+            .unwrap(),
+            r#"{"vy":0.1,"vx":0.3,"vyaw":-0.7}"#
+        );
+    }
+
+    fn reply(uuid: &str, status: i32, body: &str) -> RpcRespMsg {
+        RpcRespMsg {
+            uuid: uuid.to_owned(),
+            header: json!({ "status": status }).to_string(),
+            body: body.to_owned(),
+        }
+    }
+
+    /// Client-side state a [`PendingCall`] updates.
+    struct Accounting {
+        response_seen: AtomicBool,
+        unmatched_replies: UnmatchedReplies,
+        timeout_streaks: TimeoutStreaks,
+    }
+
+    impl Accounting {
+        fn new() -> Self {
+            Self {
+                response_seen: AtomicBool::new(false),
+                unmatched_replies: UnmatchedReplies::new(4),
+                timeout_streaks: TimeoutStreaks::default(),
+            }
+        }
+
+        /// Run the receive loop for `request_id` over `replies`, then a stream
+        /// that never yields.
+        async fn receive(
+            &self,
+            request_id: &str,
+            replies: Vec<std::result::Result<RpcRespMsg, FromUtf8Error>>,
+        ) -> Result<String> {
+            self.attempt(request_id, replies, true).await
+        }
+
+        /// Like [`Self::receive`], for one attempt of a possibly retried call.
+        async fn attempt(
+            &self,
+            request_id: &str,
+            replies: Vec<std::result::Result<RpcRespMsg, FromUtf8Error>>,
+            final_attempt: bool,
+        ) -> Result<String> {
+            let mut responses = stream::iter(replies).chain(stream::pending());
+            self.call(request_id, Duration::from_millis(50), final_attempt)
+                .receive(&mut responses)
+                .await
+        }
+
+        fn call<'a>(
+            &'a self,
+            request_id: &'a str,
+            timeout: Duration,
+            final_attempt: bool,
+        ) -> PendingCall<'a> {
+            PendingCall {
+                service_topic: "rt/TestApiTopic",
+                api_id: 2000,
+                request_id,
+                timeout,
+                final_attempt,
+                max_response_bytes: 1024,
+                response_seen: &self.response_seen,
+                unmatched_replies: &self.unmatched_replies,
+                timeout_streaks: &self.timeout_streaks,
+            }
+        }
+
+        /// Run a call whose every attempt times out, retried like `call_query`.
+        async fn retried_call_timing_out(&self) {
+            for attempt in 0..=DISCOVERY_RETRIES {
+                let request_id = format!("req-{attempt}");
+                let final_attempt = attempt == DISCOVERY_RETRIES;
+                let result = self.attempt(&request_id, vec![], final_attempt).await;
+                assert!(matches!(
+                    result,
+                    Err(BoosterError::Rpc(RpcError::Timeout { .. }))
+                ));
+            }
+        }
+
+        fn timeout_streak(&self) -> u32 {
+            let streaks = self.timeout_streaks.0.lock().unwrap();
+            streaks.get(&2000).copied().unwrap_or(0)
+        }
+    }
+
+    #[test]
+    fn scoped_timeout_replaces_default_but_not_a_longer_minimum() {
+        let secs = Duration::from_secs;
+        assert_eq!(resolve_call_timeout(None, None, secs(5)), secs(5));
+        assert_eq!(resolve_call_timeout(None, Some(secs(1)), secs(5)), secs(1));
+        assert_eq!(
+            resolve_call_timeout(Some(secs(10)), None, secs(5)),
+            secs(10)
+        );
+        assert_eq!(
+            resolve_call_timeout(Some(secs(10)), Some(secs(1)), secs(5)),
+            secs(10)
+        );
+        assert_eq!(
+            resolve_call_timeout(Some(secs(10)), Some(secs(30)), secs(5)),
+            secs(30)
+        );
+    }
+
+    #[test]
+    fn startup_wait_from_construction_subtracts_elapsed_time() {
+        let wait = Duration::from_millis(3000);
+        // Counted from the first call, the full wait is slept.
+        assert_eq!(remaining_startup_wait(wait, None), wait);
+        // Counted from construction, 2 s already elapsed leaves a 1 s sleep.
+        assert_eq!(
+            remaining_startup_wait(wait, Some(Duration::from_millis(2000))),
+            Duration::from_millis(1000)
+        );
+        assert_eq!(
+            remaining_startup_wait(wait, Some(Duration::from_secs(10))),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn timeout_streak_warns_once_per_streak() {
+        let streaks = TimeoutStreaks::default();
+        let warnings = |api_id| {
+            (0..UNSUPPORTED_API_TIMEOUTS * 2)
+                .filter(|_| streaks.record_timeout(api_id))
+                .count()
+        };
+        assert_eq!(warnings(2000), 1);
+        // Other api ids keep their own count.
+        assert!(!streaks.record_timeout(2001));
+
+        streaks.reset(2000);
+        assert_eq!(warnings(2000), 1);
+    }
+
+    #[tokio::test]
+    async fn retried_call_counts_one_timeout() {
+        let accounting = Accounting::new();
+        accounting.retried_call_timing_out().await;
+
+        // Only the final attempt counts, so one call stays below the warning.
+        assert_eq!(accounting.timeout_streak(), 1);
+    }
+
+    #[tokio::test]
+    async fn timeout_streak_spans_retried_calls_and_resets_on_a_retry_reply() {
+        let accounting = Accounting::new();
+        for _ in 0..UNSUPPORTED_API_TIMEOUTS {
+            accounting.retried_call_timing_out().await;
+        }
+        assert_eq!(accounting.timeout_streak(), UNSUPPORTED_API_TIMEOUTS);
+
+        // A reply to a retried attempt ends the streak like any other reply.
+        let result = accounting.attempt("retry", vec![], false).await;
+        assert!(matches!(
+            result,
+            Err(BoosterError::Rpc(RpcError::Timeout { .. }))
+        ));
+        accounting
+            .attempt("retry-2", vec![Ok(reply("retry-2", 0, "{}"))], false)
+            .await
+            .unwrap();
+        assert_eq!(accounting.timeout_streak(), 0);
+    }
+
+    #[tokio::test]
+    async fn rpc_reply_arrives_while_a_subscription_is_flooded() {
+        // Never drained: its reader thread blocks once the channel is full.
+        let _flooded =
+            spawn_reader("flooded".to_owned(), 1, || Ok::<_, &str>(Some(0_u32))).unwrap();
+        let mut next = 0_u32;
+        let mut live = spawn_reader("live".to_owned(), 8, move || {
+            next += 1;
+            Ok::<_, &str>(Some(next))
+        })
+        .unwrap();
+
+        // Replies arrive from another thread, like DDS samples do.
+        let (sender, receiver) = tokio::sync::mpsc::channel(1);
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            let _ = sender.blocking_send(Ok::<_, FromUtf8Error>(reply("req", 0, "{}")));
+        });
+        let mut replies = Box::pin(stream::unfold(receiver, |mut receiver| async {
+            receiver.recv().await.map(|reply| (reply, receiver))
+        }));
+
+        let accounting = Accounting::new();
+        let call = accounting.call("req", Duration::from_secs(1), true);
+        let (body, ()) = tokio::join!(call.receive(&mut replies), async {
+            for expected in 1..=50 {
+                let sample = live
+                    .next_with_timeout(Duration::from_millis(100))
+                    .await
+                    .unwrap();
+                assert_eq!(sample, expected);
+            }
+        },);
+        assert_eq!(body.unwrap(), "{}");
+    }
+
+    #[test]
+    fn unmatched_replies_separate_late_from_orphaned() {
+        let replies = UnmatchedReplies::new(2);
+        replies.record_timeout("a");
+        replies.record_timeout("b");
+        replies.record_timeout("c");
+
+        assert!(replies.record_reply("c"));
+        assert!(replies.record_reply("b"));
+        assert!(!replies.record_reply("a"));
+        assert!(!replies.record_reply("unknown"));
+        assert_eq!(replies.late.load(std::sync::atomic::Ordering::Relaxed), 2);
+        assert_eq!(
+            replies.orphaned.load(std::sync::atomic::Ordering::Relaxed),
+            2
+        );
+    }
+
+    #[test]
+    fn classify_reply_separates_foreign_intermediate_and_final() {
+        assert_eq!(
+            classify_reply("a", &reply("b", 0, "{}")),
+            ReplyKind::OtherRequest
+        );
+        assert_eq!(
+            classify_reply("a", &reply("a", -1, "")),
+            ReplyKind::Intermediate
+        );
+        assert_eq!(
+            classify_reply("a", &reply("a", 500, "boom")),
+            ReplyKind::Final { status_code: 500 }
+        );
+    }
+
+    #[tokio::test]
+    async fn duplicate_replies_resolve_to_the_first_final_one() {
+        let accounting = Accounting::new();
+        let body = accounting
+            .receive(
+                "req",
+                vec![
+                    Ok(reply("old", 0, r#"{"stale":true}"#)),
+                    Ok(reply("req", -1, "")),
+                    Ok(reply("req", 0, r#"{"n":1}"#)),
+                    Ok(reply("req", 0, r#"{"n":2}"#)),
+                ],
+            )
+            .await
+            .unwrap();
+        assert_eq!(body, r#"{"n":1}"#);
+        assert!(accounting.response_seen.load(Ordering::SeqCst));
+        assert_eq!(
+            accounting
+                .unmatched_replies
+                .orphaned
+                .load(Ordering::Relaxed),
+            1
+        );
+
+        // The duplicate is foreign to the next call, which uses a new uuid.
+        let next = accounting
+            .receive(
+                "next",
+                vec![
+                    Ok(reply("req", 0, r#"{"n":2}"#)),
+                    Ok(reply("next", 409, "busy")),
+                ],
+            )
+            .await;
+        assert!(matches!(
+            next,
+            Err(BoosterError::Rpc(RpcError::Conflict(message))) if message == "busy"
+        ));
+    }
+
+    #[tokio::test]
+    async fn timed_out_call_counts_its_late_reply() {
+        let accounting = Accounting::new();
+        let first = accounting.receive("slow", vec![]).await;
+        assert!(matches!(
+            first,
+            Err(BoosterError::Rpc(RpcError::Timeout { .. }))
+        ));
+
+        let body = accounting
+            .receive(
+                "fast",
+                vec![Ok(reply("slow", 0, "{}")), Ok(reply("fast", 0, "{}"))],
+            )
+            .await
+            .unwrap();
+        assert_eq!(body, "{}");
+        assert_eq!(accounting.unmatched_replies.late.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn oversized_payload_is_rejected() {
+        let body = "x".repeat(17);
+        assert!(matches!(
+            check_response_size(&reply("a", 0, &body), 16),
+            Err(RpcError::InternalServerError(_))
+        ));
+        assert!(check_response_size(&reply("a", 0, &body[..16]), 16).is_ok());
+    }
+
+    #[tokio::test]
+    async fn oversized_reply_fails_the_call() {
+        let accounting = Accounting::new();
+        let result = accounting
+            .receive("req", vec![Ok(reply("req", 0, &"x".repeat(2048)))])
+            .await;
+        assert!(matches!(
+            result,
+            Err(BoosterError::Rpc(RpcError::InternalServerError(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn undecodable_reply_fails_the_call() {
+        let invalid_utf8 = String::from_utf8(vec![b'{', 0xff, 0xfe, b'}']).unwrap_err();
+        let accounting = Accounting::new();
+        let result = accounting
+            .receive(
+                "req",
+                vec![Err(invalid_utf8), Ok(reply("req", 0, r#"{"mode":1}"#))],
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(BoosterError::Rpc(RpcError::InternalServerError(message)))
+                if message.contains("utf-8")
+        ));
+    }
+
+    #[test]
+    fn truncated_json_fails_without_panicking() {
+        assert!(decode_response_body::<serde_json::Value>(r#"{"mode":1"#).is_err());
     }
 
     #[test]
     fn non_json_body_fails_deserialization() {
-        let parsed = decode_response_body::<EmptyResponse>("not-json");
+        let parsed = decode_response_body::<VoidResponse>("not-json");
         assert!(parsed.is_err());
     }
 }
@@ -28,6 +28,31 @@ impl TopicSpec {
             )
             .map_err(|err| DdsError::InitializationFailed(err.to_string()).into())
     }
+
+    /// Copy of this spec with `prefix` applied; see [`prefixed_topic_name`].
+    #[must_use]
+    pub fn with_prefix(&self, prefix: &str) -> Self {
+        Self {
+            name: prefixed_topic_name(prefix, &self.name),
+            ..self.clone()
+        }
+    }
+}
+
+/// Insert a robot namespace into a topic name.
+///
+/// The prefix goes after the `rt/` ROS namespace when present, so prefix
+/// `robot1` maps `rt/LocoApiTopicReq` to `rt/robot1/LocoApiTopicReq`. Slashes
+/// around the prefix are ignored and an empty prefix leaves `name` unchanged.
+pub fn prefixed_topic_name(prefix: &str, name: &str) -> String {
+    let prefix = prefix.trim_matches('/');
+    if prefix.is_empty() {
+        return name.to_owned();
+    }
+    match name.strip_prefix("rt/") {
+        Some(rest) => format!("rt/{prefix}/{rest}"),
+        None => format!("{prefix}/{name}"),
+    }
 }
 
 pub const TYPE_RPC_REQ: &str = "booster_msgs::msg::dds_::RpcReqMsg_";
@@ -185,3 +210,30 @@ pub fn lui_asr_chunk_topic() -> TopicSpec {
         kind: TopicKind::NoKey,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn topic_prefix_goes_after_ros_namespace() {
+        assert_eq!(
+            prefixed_topic_name("robot1", "rt/motion_state"),
+            "rt/robot1/motion_state"
+        );
+        assert_eq!(
+            loco_request_topic().with_prefix("/robot1/").name,
+            "rt/robot1/LocoApiTopicReq"
+        );
+        assert_eq!(prefixed_topic_name("robot1", "custom"), "robot1/custom");
+    }
+
+    #[test]
+    fn empty_topic_prefix_keeps_default_names() {
+        assert_eq!(
+            prefixed_topic_name("", "rt/motion_state"),
+            "rt/motion_state"
+        );
+        assert_eq!(motion_state_topic().with_prefix("").name, "rt/motion_state");
+    }
+}
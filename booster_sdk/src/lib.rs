@@ -10,6 +10,9 @@
 //! - [`dds`]: DDS transport primitives for communicating with Booster services.
 //! - [`types`]: core data structures, error types, and helper utilities shared across
 //!   the SDK.
+//! - [`repl`]: text command dispatcher for manual testing on hardware.
+//! - [`task`]: handles that stop background helper tasks when dropped.
+//! - `schema` (feature `schema`): JSON Schemas for the wire types.
 //!
 //! ## Getting Started
 //!
@@ -32,4 +35,8 @@
 
 pub mod client;
 pub mod dds;
+pub mod repl;
+#[cfg(feature = "schema")]
+pub mod schema;
+pub mod task;
 pub mod types;
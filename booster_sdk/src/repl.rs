@@ -0,0 +1,236 @@
+//! Line-based command dispatcher for manual and end-to-end testing.
+//!
+//! Each line is a command word followed by whitespace-separated arguments:
+//!
+//! ```text
+//! move 0.3 0 0        # vx vy vyaw
+//! mode walking        # damping | prepare | walking | custom | soccer
+//! dance nezha         # new_year | nezha | towards_future | ... | stop
+//! gripper open left   # open | close, left | right
+//! status
+//! ```
+//!
+//! Names are matched case-insensitively and `_`/`-` are ignored, so
+//! `towards_future`, `TowardsFuture` and `towards-future` are equivalent.
+
+use std::fmt::Debug;
+use std::str::FromStr;
+
+use crate::client::BoosterClient;
+use crate::client::loco::GripperCommand;
+use crate::types::{BoosterError, DanceId, Hand, Result, RobotMode};
+
+/// A parsed command line.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Command {
+    /// `move <vx> <vy> <vyaw>`
+    Move { vx: f32, vy: f32, vyaw: f32 },
+    /// `mode <mode>`
+    Mode(RobotMode),
+    /// `dance <dance>`
+    Dance(DanceId),
+    /// `gripper <open|close> <hand>`
+    Gripper { open: bool, hand: Hand },
+    /// `status`
+    Status,
+}
+
+impl Command {
+    /// Parse a single command line.
+    ///
+    /// Returns [`BoosterError::Validation`] for unknown commands, unknown
+    /// names and wrong argument counts.
+    pub fn parse(line: &str) -> Result<Self> {
+        let mut words = line.split_whitespace();
+        let Some(command) = words.next() else {
+            return Err(BoosterError::Validation("empty command".to_owned()));
+        };
+        let args: Vec<&str> = words.collect();
+
+        match (command.to_ascii_lowercase().as_str(), args.as_slice()) {
+            ("move", [vx, vy, vyaw]) => Ok(Self::Move {
+                vx: parse_f32("vx", vx)?,
+                vy: parse_f32("vy", vy)?,
+                vyaw: parse_f32("vyaw", vyaw)?,
+            }),
+            ("mode", [word]) => match word.parse() {
+                Ok(mode) if mode != RobotMode::Unknown => Ok(Self::Mode(mode)),
+                _ => Err(unknown_name("mode", word, &commandable_modes())),
+            },
+            ("dance", [dance]) => parse_name("dance", dance, DanceId::ALL).map(Self::Dance),
+            ("gripper", [action, hand]) => {
+                let open = match action.to_ascii_lowercase().as_str() {
+                    "open" => true,
+                    "close" => false,
+                    _ => {
+                        return Err(BoosterError::Validation(format!(
+                            "unknown gripper action `{action}` (expected open or close)"
+                        )));
+                    }
+                };
+                let hand = parse_name("hand", hand, &[Hand::Left, Hand::Right])?;
+                Ok(Self::Gripper { open, hand })
+            }
+            ("status", []) => Ok(Self::Status),
+            ("move" | "mode" | "dance" | "gripper" | "status", _) => Err(BoosterError::Validation(
+                format!("usage: {}", usage(command)),
+            )),
+            _ => Err(BoosterError::Validation(format!(
+                "unknown command `{command}` (expected move, mode, dance, gripper or status)"
+            ))),
+        }
+    }
+}
+
+/// Parse `line` and run it against `client`.
+///
+/// Returns a short human-readable description of what was done.
+pub async fn dispatch(client: &BoosterClient, line: &str) -> Result<String> {
+    match Command::parse(line)? {
+        Command::Move { vx, vy, vyaw } => {
+            client.move_robot(vx, vy, vyaw).await?;
+            Ok(format!("moving at vx={vx} vy={vy} vyaw={vyaw}"))
+        }
+        Command::Mode(mode) => {
+            client.change_mode(mode).await?;
+            Ok(format!("changed mode to {mode:?}"))
+        }
+        Command::Dance(dance) => {
+            client.dance(dance).await?;
+            Ok(format!("started dance {dance:?}"))
+        }
+        Command::Gripper { open, hand } => {
+            let (command, verb) = if open {
+                (GripperCommand::open(hand), "opened")
+            } else {
+                (GripperCommand::close(hand), "closed")
+            };
+            client.publish_gripper_command(&command)?;
+            Ok(format!("{verb} {hand:?} gripper"))
+        }
+        Command::Status => {
+            let status = client.get_status().await?;
+            let mode = status.current_mode_enum().map_or_else(
+                || status.current_mode.to_string(),
+                |mode| format!("{mode:?}"),
+            );
+            Ok(format!(
+                "mode={mode} body_control={} actions={:?}",
+                status.current_body_control,
+                status.current_actions_labeled()
+            ))
+        }
+    }
+}
+
+fn usage(command: &str) -> &'static str {
+    match command.to_ascii_lowercase().as_str() {
+        "move" => "move <vx> <vy> <vyaw>",
+        "mode" => "mode <mode>",
+        "dance" => "dance <dance>",
+        "gripper" => "gripper <open|close> <left|right>",
+        _ => "status",
+    }
+}
+
+fn parse_f32(name: &str, value: &str) -> Result<f32> {
+    value
+        .parse()
+        .map_err(|_| BoosterError::Validation(format!("{name} must be a number, got `{value}`")))
+}
+
+/// Parse `word` with `T`'s `FromStr`, listing `expected` on failure.
+fn parse_name<T: FromStr + Debug>(kind: &str, word: &str, expected: &[T]) -> Result<T> {
+    word.parse().map_err(|_| unknown_name(kind, word, expected))
+}
+
+fn unknown_name<T: Debug>(kind: &str, word: &str, expected: &[T]) -> BoosterError {
+    let names: Vec<String> = expected.iter().map(|name| format!("{name:?}")).collect();
+    BoosterError::Validation(format!(
+        "unknown {kind} `{word}` (expected one of {})",
+        names.join(", ")
+    ))
+}
+
+/// Modes that can be requested, i.e. every mode except `Unknown`.
+fn commandable_modes() -> Vec<RobotMode> {
+    RobotMode::ALL
+        .iter()
+        .copied()
+        .filter(|mode| *mode != RobotMode::Unknown)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_each_command() {
+        assert_eq!(
+            Command::parse("move 0.3 0 -0.5").unwrap(),
+            Command::Move {
+                vx: 0.3,
+                vy: 0.0,
+                vyaw: -0.5
+            }
+        );
+        assert_eq!(
+            Command::parse("mode walking").unwrap(),
+            Command::Mode(RobotMode::Walking)
+        );
+        assert_eq!(
+            Command::parse("dance nezha").unwrap(),
+            Command::Dance(DanceId::Nezha)
+        );
+        assert_eq!(
+            Command::parse("  gripper open left ").unwrap(),
+            Command::Gripper {
+                open: true,
+                hand: Hand::Left
+            }
+        );
+        assert_eq!(Command::parse("status").unwrap(), Command::Status);
+    }
+
+    #[test]
+    fn names_ignore_case_and_separators() {
+        for line in [
+            "dance towards_future",
+            "dance TowardsFuture",
+            "DANCE towards-future",
+        ] {
+            assert_eq!(
+                Command::parse(line).unwrap(),
+                Command::Dance(DanceId::TowardsFuture)
+            );
+        }
+        assert_eq!(
+            Command::parse("gripper CLOSE Right").unwrap(),
+            Command::Gripper {
+                open: false,
+                hand: Hand::Right
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        for line in [
+            "",
+            "fly",
+            "move 0.3 0",
+            "move fast 0 0",
+            "mode unknown",
+            "dance tango",
+            "gripper wiggle left",
+            "gripper open middle",
+            "status now",
+        ] {
+            assert!(
+                matches!(Command::parse(line), Err(BoosterError::Validation(_))),
+                "{line:?} should be rejected"
+            );
+        }
+    }
+}
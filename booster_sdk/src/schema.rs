@@ -0,0 +1,123 @@
+//! JSON Schemas for the request and response payloads exchanged with the robot.
+//!
+//! Available with the `schema` feature.
+
+use schemars::{JsonSchema, schema_for};
+use serde_json::{Map, Value};
+
+use crate::client::{ai, light_control, loco, vision, x5_camera};
+use crate::types;
+
+/// Describe `api_id_enum!` types as the integers they are sent as.
+macro_rules! integer_enum_schema {
+    ($($module:ident::$name:ident),+ $(,)?) => {
+        $(
+            impl JsonSchema for $module::$name {
+                fn schema_name() -> std::borrow::Cow<'static, str> {
+                    stringify!($name).into()
+                }
+
+                fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+                    let values: Vec<i32> = Self::ALL.iter().map(|&value| value.into()).collect();
+                    schemars::json_schema!({
+                        "type": "integer",
+                        "format": "int32",
+                        "enum": values,
+                    })
+                }
+            }
+        )+
+    };
+}
+
+integer_enum_schema!(
+    types::LocoApiId,
+    types::BodyControl,
+    types::Action,
+    types::Frame,
+    types::HandAction,
+    types::BoosterHandType,
+    types::DanceId,
+    types::WholeBodyDanceId,
+    types::JointOrder,
+    types::GripperControlMode,
+    types::RobotMode,
+    types::GripperMode,
+    x5_camera::X5CameraApiId,
+    x5_camera::CameraSetMode,
+    x5_camera::CameraControlStatus,
+    light_control::LightApiId,
+    vision::VisionApiId,
+    ai::AiApiId,
+    ai::LuiApiId,
+);
+
+fn insert<T: JsonSchema>(schemas: &mut Map<String, Value>, name: &str) {
+    schemas.insert(name.to_owned(), schema_for!(T).to_value());
+}
+
+/// Schemas for every public wire type, keyed by type name.
+///
+/// Types that share a name across services are prefixed with the service,
+/// e.g. `X5CameraGetStatusResponse`.
+#[must_use]
+pub fn export() -> Value {
+    let mut schemas = Map::new();
+
+    // Locomotion
+    insert::<types::MoveCommand>(&mut schemas, "MoveCommand");
+    insert::<types::Posture>(&mut schemas, "Posture");
+    insert::<types::Transform>(&mut schemas, "Transform");
+    insert::<types::GripperMotionParameter>(&mut schemas, "GripperMotionParameter");
+    insert::<types::DexterousFingerParameter>(&mut schemas, "DexterousFingerParameter");
+    insert::<types::CustomTrainedTraj>(&mut schemas, "CustomTrainedTraj");
+    insert::<types::GetModeResponse>(&mut schemas, "GetModeResponse");
+    insert::<types::GetStatusResponse>(&mut schemas, "GetStatusResponse");
+    insert::<types::GetRobotInfoResponse>(&mut schemas, "GetRobotInfoResponse");
+    insert::<types::LoadCustomTrainedTrajResponse>(&mut schemas, "LoadCustomTrainedTrajResponse");
+    insert::<types::RobotMode>(&mut schemas, "RobotMode");
+    insert::<loco::GripperCommand>(&mut schemas, "GripperCommand");
+
+    // AI and LUI
+    insert::<ai::StartAiChatParameter>(&mut schemas, "StartAiChatParameter");
+    insert::<ai::SpeakParameter>(&mut schemas, "SpeakParameter");
+    insert::<ai::LuiTtsConfig>(&mut schemas, "LuiTtsConfig");
+    insert::<ai::LuiTtsParameter>(&mut schemas, "LuiTtsParameter");
+    insert::<ai::Subtitle>(&mut schemas, "Subtitle");
+    insert::<ai::AsrChunk>(&mut schemas, "AsrChunk");
+
+    // Light control
+    insert::<light_control::SetLedLightColorParameter>(&mut schemas, "SetLedLightColorParameter");
+
+    // Vision
+    insert::<vision::StartVisionServiceParameter>(&mut schemas, "StartVisionServiceParameter");
+    insert::<vision::GetDetectionObjectParameter>(&mut schemas, "GetDetectionObjectParameter");
+    insert::<vision::DetectResults>(&mut schemas, "DetectResults");
+
+    // X5 camera
+    insert::<x5_camera::ChangeModeParameter>(&mut schemas, "X5CameraChangeModeParameter");
+    insert::<x5_camera::GetStatusResponse>(&mut schemas, "X5CameraGetStatusResponse");
+
+    Value::Object(schemas)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::export;
+
+    #[test]
+    fn export_describes_wire_types() {
+        let schemas = export();
+        let move_command = &schemas["MoveCommand"];
+        assert_eq!(move_command["type"], "object");
+        assert!(move_command["properties"]["vyaw"].is_object());
+
+        let mode = &schemas["RobotMode"];
+        assert_eq!(mode["type"], "integer");
+        assert_eq!(mode["enum"][0], -1);
+        assert_eq!(
+            schemars::schema_for!(crate::types::RobotMode).as_value()["title"],
+            "RobotMode"
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! Handles for background tasks spawned by SDK helpers.
+
+use std::future::Future;
+
+use tokio::runtime::Handle;
+use tokio::task::JoinHandle;
+
+/// Owner of a background tokio task that is aborted when dropped.
+///
+/// Helpers that keep working after they return, such as streams and
+/// animations, hand out a `TaskHandle` so the work stops as soon as the caller
+/// loses interest. Cancellation takes effect at the task's next `.await`.
+#[derive(Debug)]
+#[must_use = "dropping a TaskHandle stops its task"]
+pub struct TaskHandle {
+    task: Option<JoinHandle<()>>,
+}
+
+impl TaskHandle {
+    /// Spawn `future` on the current tokio runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics when called outside a tokio runtime, like [`tokio::spawn`].
+    pub fn spawn<F>(future: F) -> Self
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        Self {
+            task: Some(tokio::spawn(future)),
+        }
+    }
+
+    /// Spawn `future` on `runtime`, for callers outside of any tokio runtime.
+    pub fn spawn_on<F>(runtime: &Handle, future: F) -> Self
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        Self {
+            task: Some(runtime.spawn(future)),
+        }
+    }
+
+    /// Abort the task now instead of on drop.
+    pub fn stop(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+
+    /// Whether the task is still running.
+    pub fn is_active(&self) -> bool {
+        self.task.as_ref().is_some_and(|task| !task.is_finished())
+    }
+}
+
+impl Drop for TaskHandle {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn dropping_handle_stops_task() {
+        let (sender, receiver) = tokio::sync::oneshot::channel::<()>();
+        let handle = TaskHandle::spawn(async move {
+            let _sender = sender;
+            std::future::pending::<()>().await;
+        });
+        assert!(handle.is_active());
+
+        drop(handle);
+        // The sender is dropped together with the aborted task.
+        let closed = tokio::time::timeout(Duration::from_secs(1), receiver).await;
+        assert!(matches!(closed, Ok(Err(_))));
+    }
+
+    #[test]
+    fn spawn_on_runs_outside_the_runtime() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let _handle = TaskHandle::spawn_on(runtime.handle(), async move {
+            sender.send(()).unwrap();
+        });
+        assert!(receiver.recv_timeout(Duration::from_secs(1)).is_ok());
+    }
+
+    #[tokio::test]
+    async fn stop_marks_handle_inactive() {
+        let mut handle = TaskHandle::spawn(std::future::pending());
+        handle.stop();
+        assert!(!handle.is_active());
+    }
+}
@@ -1,8 +1,10 @@
 //! B1 locomotion, kinematics, and high-level API types.
 
+use std::collections::HashSet;
+
 use serde::{Deserialize, Serialize};
 
-use super::{Hand, RobotMode};
+use super::{BoosterError, Hand, Result, RobotMode};
 
 crate::api_id_enum! {
     /// Locomotion RPC API identifiers.
@@ -146,6 +148,31 @@ crate::api_id_enum! {
     }
 }
 
+/// Change in the set of actions the robot reports as running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ActionEvent {
+    Started(Action),
+    Finished(Action),
+}
+
+impl ActionEvent {
+    /// Events that turn the `previous` action snapshot into `current`.
+    ///
+    /// Finished actions are listed before started ones, each in snapshot order.
+    #[must_use]
+    pub fn diff(previous: &[Action], current: &[Action]) -> Vec<Self> {
+        let finished = previous
+            .iter()
+            .filter(|action| !current.contains(action))
+            .map(|&action| Self::Finished(action));
+        let started = current
+            .iter()
+            .filter(|action| !previous.contains(action))
+            .map(|&action| Self::Started(action));
+        finished.chain(started).collect()
+    }
+}
+
 crate::api_id_enum! {
     /// Joint ordering identifiers for model compatibility.
     JointOrder {
@@ -154,6 +181,23 @@ crate::api_id_enum! {
     }
 }
 
+impl JointOrder {
+    /// Number of actuated joints of the B1 in this ordering.
+    ///
+    /// Both orderings cover the same joints: 2 head, 4 per arm, 1 waist and
+    /// 6 per leg, matching `B1JointCnt` in the vendor booster_robotics_sdk.
+    #[must_use]
+    pub fn joint_count(self) -> usize {
+        const HEAD: usize = 2;
+        const ARM: usize = 4;
+        const WAIST: usize = 1;
+        const LEG: usize = 6;
+        match self {
+            Self::MuJoCo | Self::IsaacLab => HEAD + 2 * ARM + WAIST + 2 * LEG,
+        }
+    }
+}
+
 crate::api_id_enum! {
     /// Gripper command mode identifiers.
     GripperControlMode {
@@ -164,6 +208,7 @@ crate::api_id_enum! {
 
 /// Cartesian position.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Position {
     pub x: f32,
     pub y: f32,
@@ -172,6 +217,7 @@ pub struct Position {
 
 /// Euler orientation in radians.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Orientation {
     pub roll: f32,
     pub pitch: f32,
@@ -180,6 +226,7 @@ pub struct Orientation {
 
 /// Position and orientation pair.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Posture {
     pub position: Position,
     pub orientation: Orientation,
@@ -187,6 +234,7 @@ pub struct Posture {
 
 /// Quaternion orientation.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Quaternion {
     pub x: f32,
     pub y: f32,
@@ -196,13 +244,211 @@ pub struct Quaternion {
 
 /// Transform with position and quaternion orientation.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Transform {
     pub position: Position,
     pub orientation: Quaternion,
 }
 
+impl From<Orientation> for Quaternion {
+    /// Convert roll/pitch/yaw (applied in Z-Y-X order) to a unit quaternion.
+    fn from(orientation: Orientation) -> Self {
+        let (sr, cr) = (orientation.roll * 0.5).sin_cos();
+        let (sp, cp) = (orientation.pitch * 0.5).sin_cos();
+        let (sy, cy) = (orientation.yaw * 0.5).sin_cos();
+
+        Self {
+            x: sr * cp * cy - cr * sp * sy,
+            y: cr * sp * cy + sr * cp * sy,
+            z: cr * cp * sy - sr * sp * cy,
+            w: cr * cp * cy + sr * sp * sy,
+        }
+    }
+}
+
+impl From<Quaternion> for Orientation {
+    /// Convert a quaternion to roll/pitch/yaw (Z-Y-X order).
+    ///
+    /// Pitch is clamped to ±90° at gimbal lock.
+    fn from(q: Quaternion) -> Self {
+        let roll = (2.0 * (q.w * q.x + q.y * q.z)).atan2(1.0 - 2.0 * (q.x * q.x + q.y * q.y));
+        let pitch = (2.0 * (q.w * q.y - q.z * q.x)).clamp(-1.0, 1.0).asin();
+        let yaw = (2.0 * (q.w * q.z + q.x * q.y)).atan2(1.0 - 2.0 * (q.y * q.y + q.z * q.z));
+
+        Self { roll, pitch, yaw }
+    }
+}
+
+impl From<Posture> for Transform {
+    fn from(posture: Posture) -> Self {
+        Self {
+            position: posture.position,
+            orientation: posture.orientation.into(),
+        }
+    }
+}
+
+impl From<Transform> for Posture {
+    fn from(transform: Transform) -> Self {
+        Self {
+            position: transform.position,
+            orientation: transform.orientation.into(),
+        }
+    }
+}
+
+/// Head `(pitch, yaw)` in radians that aims the head at `point`.
+///
+/// `point` is relative to the head pivot, with body-frame axes: x forward,
+/// y left, z up. Positive yaw turns left and positive pitch looks down,
+/// matching `rotate_head`.
+#[must_use]
+pub fn head_angles_for_point(point: Position) -> (f32, f32) {
+    let horizontal = point.x.hypot(point.y);
+    let pitch = -point.z.atan2(horizontal);
+    let yaw = point.y.atan2(point.x);
+    (pitch, yaw)
+}
+
+/// Base velocity command in body frame.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MoveCommand {
+    /// Forward velocity in m/s.
+    pub vx: f32,
+    /// Lateral velocity in m/s.
+    pub vy: f32,
+    /// Yaw rate in rad/s.
+    pub vyaw: f32,
+}
+
+impl MoveCommand {
+    /// Zero velocity.
+    pub const STOP: Self = Self {
+        vx: 0.0,
+        vy: 0.0,
+        vyaw: 0.0,
+    };
+
+    #[must_use]
+    pub fn new(vx: f32, vy: f32, vyaw: f32) -> Self {
+        Self { vx, vy, vyaw }
+    }
+
+    /// Linear interpolation towards `target`, with `t` clamped to `0.0..=1.0`.
+    #[must_use]
+    pub fn lerp(self, target: Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        Self {
+            vx: self.vx + (target.vx - self.vx) * t,
+            vy: self.vy + (target.vy - self.vy) * t,
+            vyaw: self.vyaw + (target.vyaw - self.vyaw) * t,
+        }
+    }
+}
+
+/// Sensitivity profile for turning joystick axes into a [`MoveCommand`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MoveScale {
+    /// Forward velocity at full deflection, in m/s.
+    pub max_vx: f32,
+    /// Lateral velocity at full deflection, in m/s.
+    pub max_vy: f32,
+    /// Yaw rate at full deflection, in rad/s.
+    pub max_vyaw: f32,
+    /// Fraction of each axis around center that maps to zero.
+    pub deadzone: f32,
+}
+
+impl MoveScale {
+    pub const SLOW: Self = Self {
+        max_vx: 0.3,
+        max_vy: 0.2,
+        max_vyaw: 0.5,
+        deadzone: 0.1,
+    };
+
+    pub const NORMAL: Self = Self {
+        max_vx: 0.6,
+        max_vy: 0.3,
+        max_vyaw: 1.0,
+        deadzone: 0.1,
+    };
+
+    pub const FAST: Self = Self {
+        max_vx: 1.0,
+        max_vy: 0.5,
+        max_vyaw: 1.5,
+        deadzone: 0.1,
+    };
+
+    /// Check that all maxima are positive and the deadzone is in `0.0..1.0`.
+    pub fn validate(&self) -> Result<()> {
+        for (name, value) in [
+            ("max_vx", self.max_vx),
+            ("max_vy", self.max_vy),
+            ("max_vyaw", self.max_vyaw),
+        ] {
+            if !(value.is_finite() && value > 0.0) {
+                return Err(BoosterError::Validation(format!(
+                    "move scale `{name}` must be positive, got {value}"
+                )));
+            }
+        }
+        if !(0.0..1.0).contains(&self.deadzone) {
+            return Err(BoosterError::Validation(format!(
+                "move scale `deadzone` must be in [0, 1), got {}",
+                self.deadzone
+            )));
+        }
+        Ok(())
+    }
+
+    /// Map axis values in `-1.0..=1.0` to a velocity command.
+    ///
+    /// Positive axes mean forward, left and counter-clockwise. Values inside
+    /// the deadzone map to zero and the remaining range is rescaled so full
+    /// deflection still reaches the maximum.
+    #[must_use]
+    pub fn scale(&self, forward: f32, lateral: f32, turn: f32) -> MoveCommand {
+        MoveCommand {
+            vx: self.shape(forward) * self.max_vx,
+            vy: self.shape(lateral) * self.max_vy,
+            vyaw: self.shape(turn) * self.max_vyaw,
+        }
+    }
+
+    fn shape(&self, axis: f32) -> f32 {
+        let axis = axis.clamp(-1.0, 1.0);
+        if axis.abs() <= self.deadzone {
+            return 0.0;
+        }
+        axis.signum() * (axis.abs() - self.deadzone) / (1.0 - self.deadzone)
+    }
+}
+
+impl Default for MoveScale {
+    fn default() -> Self {
+        Self::NORMAL
+    }
+}
+
+/// Neutral hand end-effector targets for `BoosterClient::home_arms`.
+///
+/// Postures are in the body frame, in meters and radians. There is no
+/// built-in default; pick a pose that is safe for the robot's setup.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ArmHomePose {
+    pub left: Posture,
+    pub right: Posture,
+    /// Time allowed for the motion, in milliseconds.
+    pub time_millis: i32,
+}
+
 /// Gripper motion command values.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GripperMotionParameter {
     pub position: i32,
     pub force: i32,
@@ -211,6 +457,7 @@ pub struct GripperMotionParameter {
 
 /// Single dexterous finger control value set.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct DexterousFingerParameter {
     pub seq: i32,
     pub angle: i32,
@@ -218,8 +465,36 @@ pub struct DexterousFingerParameter {
     pub speed: i32,
 }
 
+impl DexterousFingerParameter {
+    /// Number of independently controlled joints on a dexterous hand.
+    pub const FINGER_COUNT: usize = 6;
+
+    /// Build one parameter per finger from `angles`, numbering `seq` from 0.
+    ///
+    /// All fingers share the same `force` and `speed`.
+    #[must_use]
+    pub fn from_angles(
+        angles: [i32; Self::FINGER_COUNT],
+        force: i32,
+        speed: i32,
+    ) -> [Self; Self::FINGER_COUNT] {
+        let mut seq = 0;
+        angles.map(|angle| {
+            let param = Self {
+                seq,
+                angle,
+                force,
+                speed,
+            };
+            seq += 1;
+            param
+        })
+    }
+}
+
 /// Response payload for `GetMode`.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GetModeResponse {
     pub mode: i32,
 }
@@ -234,6 +509,7 @@ impl GetModeResponse {
 
 /// Response payload for `GetStatus`.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GetStatusResponse {
     pub current_mode: i32,
     pub current_body_control: i32,
@@ -262,10 +538,50 @@ impl GetStatusResponse {
             .filter_map(|value| Action::try_from(value).ok())
             .collect()
     }
+
+    /// Pair every code in `current_actions` with its [`Action`], if known.
+    ///
+    /// Unlike [`Self::current_actions_enum`], unknown codes are kept so they
+    /// can still be shown.
+    #[must_use]
+    pub fn current_actions_labeled(&self) -> Vec<(i32, Option<Action>)> {
+        self.current_actions
+            .iter()
+            .map(|&value| (value, Action::try_from(value).ok()))
+            .collect()
+    }
+
+    /// Known actions in `current_actions`, without duplicates.
+    ///
+    /// Unknown codes are dropped here; see [`Self::unknown_actions`].
+    #[must_use]
+    pub fn action_set(&self) -> HashSet<Action> {
+        self.current_actions
+            .iter()
+            .filter_map(|&value| Action::try_from(value).ok())
+            .collect()
+    }
+
+    /// Codes in `current_actions` that do not map to a known [`Action`].
+    #[must_use]
+    pub fn unknown_actions(&self) -> HashSet<i32> {
+        self.current_actions
+            .iter()
+            .copied()
+            .filter(|&value| Action::try_from(value).is_err())
+            .collect()
+    }
+
+    /// Whether `action` is currently running.
+    #[must_use]
+    pub fn contains_action(&self, action: Action) -> bool {
+        self.current_actions.contains(&i32::from(action))
+    }
 }
 
 /// Basic robot identity and version information.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GetRobotInfoResponse {
     pub name: String,
     pub nickname: String,
@@ -276,14 +592,36 @@ pub struct GetRobotInfoResponse {
 
 /// Model parameters used by custom trajectories.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CustomModelParams {
     pub action_scale: Vec<f64>,
     pub kp: Vec<f64>,
     pub kd: Vec<f64>,
 }
 
+impl CustomModelParams {
+    /// Check that every vector has one entry per joint of `order`.
+    pub fn validate(&self, order: JointOrder) -> Result<()> {
+        let expected = order.joint_count();
+        for (name, values) in [
+            ("action_scale", &self.action_scale),
+            ("kp", &self.kp),
+            ("kd", &self.kd),
+        ] {
+            if values.len() != expected {
+                return Err(BoosterError::Validation(format!(
+                    "custom model `{name}` has {} entries, but {order:?} has {expected} joints",
+                    values.len()
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Model metadata for custom trajectories.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CustomModel {
     pub file_path: String,
     pub params: Vec<CustomModelParams>,
@@ -292,6 +630,7 @@ pub struct CustomModel {
 
 /// Payload used to load a custom trained trajectory.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CustomTrainedTraj {
     pub traj_file_path: String,
     pub model: CustomModel,
@@ -299,9 +638,326 @@ pub struct CustomTrainedTraj {
 
 /// Response payload for `LoadCustomTrainedTraj`.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct LoadCustomTrainedTrajResponse {
     pub tid: String,
 }
 
-/// Convenience alias matching the C++ naming.
+/// Alias matching the C++ SDK naming; identical to [`Hand`].
+///
+/// Pass `Hand::Left` or `Hand::Right`; use [`Hand::index`] for the raw value.
 pub type HandIndex = Hand;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn params(len: usize) -> CustomModelParams {
+        CustomModelParams {
+            action_scale: vec![0.25; len],
+            kp: vec![40.0; len],
+            kd: vec![1.0; len],
+        }
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-5,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn head_angles_point_at_known_directions() {
+        use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+        let point = |x, y, z| Position { x, y, z };
+
+        let (pitch, yaw) = head_angles_for_point(point(2.0, 0.0, 0.0));
+        assert_close(pitch, 0.0);
+        assert_close(yaw, 0.0);
+
+        let (pitch, yaw) = head_angles_for_point(point(0.0, 1.0, 0.0));
+        assert_close(pitch, 0.0);
+        assert_close(yaw, FRAC_PI_2);
+
+        let (pitch, yaw) = head_angles_for_point(point(1.0, 0.0, 1.0));
+        assert_close(pitch, -FRAC_PI_4);
+        assert_close(yaw, 0.0);
+
+        let (pitch, _) = head_angles_for_point(point(1.0, 0.0, -1.0));
+        assert_close(pitch, FRAC_PI_4);
+    }
+
+    #[test]
+    fn posture_transform_round_trip() {
+        let posture = Posture {
+            position: Position {
+                x: 0.3,
+                y: -0.2,
+                z: 0.1,
+            },
+            orientation: Orientation {
+                roll: 0.4,
+                pitch: -0.3,
+                yaw: 1.2,
+            },
+        };
+
+        let transform = Transform::from(posture);
+        let q = transform.orientation;
+        assert_close(q.x * q.x + q.y * q.y + q.z * q.z + q.w * q.w, 1.0);
+        assert_eq!(transform.position, posture.position);
+
+        let back = Posture::from(transform);
+        assert_eq!(back.position, posture.position);
+        assert_close(back.orientation.roll, posture.orientation.roll);
+        assert_close(back.orientation.pitch, posture.orientation.pitch);
+        assert_close(back.orientation.yaw, posture.orientation.yaw);
+    }
+
+    #[test]
+    fn yaw_only_orientation_maps_to_z_rotation() {
+        let q = Quaternion::from(Orientation {
+            roll: 0.0,
+            pitch: 0.0,
+            yaw: std::f32::consts::FRAC_PI_2,
+        });
+        assert_close(q.x, 0.0);
+        assert_close(q.y, 0.0);
+        assert_close(q.z, std::f32::consts::FRAC_1_SQRT_2);
+        assert_close(q.w, std::f32::consts::FRAC_1_SQRT_2);
+    }
+
+    #[test]
+    fn move_command_lerp_interpolates_and_clamps() {
+        let start = MoveCommand::new(0.0, 0.2, -0.4);
+        let target = MoveCommand::new(0.5, 0.0, 0.4);
+
+        let half = start.lerp(target, 0.5);
+        assert_close(half.vx, 0.25);
+        assert_close(half.vy, 0.1);
+        assert_close(half.vyaw, 0.0);
+
+        assert_eq!(start.lerp(target, -1.0), start);
+        assert_eq!(start.lerp(target, 2.0), target);
+    }
+
+    #[test]
+    fn action_event_diff_reports_started_and_finished() {
+        let previous = [Action::HandWave, Action::DanceNezha];
+        let current = [Action::DanceNezha, Action::GestureCheer];
+        assert_eq!(
+            ActionEvent::diff(&previous, &current),
+            vec![
+                ActionEvent::Finished(Action::HandWave),
+                ActionEvent::Started(Action::GestureCheer),
+            ]
+        );
+        assert!(ActionEvent::diff(&current, &current).is_empty());
+    }
+
+    #[test]
+    fn posture_and_transform_wire_format() {
+        let position = Position {
+            x: 0.5,
+            y: -0.25,
+            z: 1.0,
+        };
+        let posture = Posture {
+            position,
+            orientation: Orientation {
+                roll: 0.0,
+                pitch: 0.5,
+                yaw: -1.0,
+            },
+        };
+        assert_eq!(
+            serde_json::to_value(posture).unwrap(),
+            json!({
+                "position": { "x": 0.5, "y": -0.25, "z": 1.0 },
+                "orientation": { "roll": 0.0, "pitch": 0.5, "yaw": -1.0 },
+            })
+        );
+
+        let transform = Transform {
+            position,
+            orientation: Quaternion {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+                w: 1.0,
+            },
+        };
+        assert_eq!(
+            serde_json::to_value(transform).unwrap(),
+            json!({
+                "position": { "x": 0.5, "y": -0.25, "z": 1.0 },
+                "orientation": { "x": 0.0, "y": 0.0, "z": 0.0, "w": 1.0 },
+            })
+        );
+    }
+
+    #[test]
+    fn command_parameters_wire_format() {
+        assert_eq!(
+            serde_json::to_value(MoveCommand::new(0.5, 0.0, -0.25)).unwrap(),
+            json!({ "vx": 0.5, "vy": 0.0, "vyaw": -0.25 })
+        );
+        assert_eq!(
+            serde_json::to_value(GripperMotionParameter {
+                position: 500,
+                force: 100,
+                speed: 300,
+            })
+            .unwrap(),
+            json!({ "position": 500, "force": 100, "speed": 300 })
+        );
+        assert_eq!(
+            serde_json::to_value(DexterousFingerParameter {
+                seq: 2,
+                angle: 800,
+                force: 200,
+                speed: 500,
+            })
+            .unwrap(),
+            json!({ "seq": 2, "angle": 800, "force": 200, "speed": 500 })
+        );
+    }
+
+    #[test]
+    fn custom_trained_traj_wire_format() {
+        let traj = CustomTrainedTraj {
+            traj_file_path: "/opt/traj.bin".to_owned(),
+            model: CustomModel {
+                file_path: "/opt/policy.onnx".to_owned(),
+                params: vec![CustomModelParams {
+                    action_scale: vec![0.25],
+                    kp: vec![40.0],
+                    kd: vec![1.0],
+                }],
+                joint_order: JointOrder::IsaacLab,
+            },
+        };
+        assert_eq!(
+            serde_json::to_value(traj).unwrap(),
+            json!({
+                "traj_file_path": "/opt/traj.bin",
+                "model": {
+                    "file_path": "/opt/policy.onnx",
+                    "params": [{ "action_scale": [0.25], "kp": [40.0], "kd": [1.0] }],
+                    "joint_order": 1,
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn move_scale_presets_are_valid() {
+        for scale in [MoveScale::SLOW, MoveScale::NORMAL, MoveScale::FAST] {
+            assert!(scale.validate().is_ok());
+        }
+        let bad_deadzone = MoveScale {
+            deadzone: 1.0,
+            ..MoveScale::NORMAL
+        };
+        assert!(bad_deadzone.validate().is_err());
+        let bad_max = MoveScale {
+            max_vy: 0.0,
+            ..MoveScale::NORMAL
+        };
+        assert!(bad_max.validate().is_err());
+    }
+
+    #[test]
+    fn move_scale_maps_extremes_and_deadzone() {
+        let scale = MoveScale::NORMAL;
+        assert_eq!(
+            scale.scale(1.0, -1.0, 1.0),
+            MoveCommand::new(0.6, -0.3, 1.0)
+        );
+        assert_eq!(
+            scale.scale(2.0, 0.0, -3.0),
+            MoveCommand::new(0.6, 0.0, -1.0)
+        );
+        assert_eq!(scale.scale(0.05, -0.1, 0.0), MoveCommand::STOP);
+
+        let halfway = scale.scale(0.55, 0.0, 0.0);
+        assert_close(halfway.vx, 0.3);
+    }
+
+    #[test]
+    fn joint_count_is_the_b1_joint_count() {
+        assert_eq!(JointOrder::MuJoCo.joint_count(), 23);
+        assert_eq!(JointOrder::IsaacLab.joint_count(), 23);
+    }
+
+    #[test]
+    fn custom_model_params_accept_matching_lengths() {
+        let order = JointOrder::IsaacLab;
+        assert!(params(order.joint_count()).validate(order).is_ok());
+    }
+
+    #[test]
+    fn custom_model_params_reject_mismatched_lengths() {
+        let order = JointOrder::MuJoCo;
+        match params(order.joint_count() - 1).validate(order) {
+            Err(BoosterError::Validation(message)) => assert!(message.contains("action_scale")),
+            other => panic!("expected validation error, got {other:?}"),
+        }
+
+        let mut mismatched = params(order.joint_count());
+        mismatched.kd.push(1.0);
+        match mismatched.validate(order) {
+            Err(BoosterError::Validation(message)) => assert!(message.contains("kd")),
+            other => panic!("expected validation error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn current_actions_labeled_keeps_unknown_codes() {
+        let status = GetStatusResponse {
+            current_mode: 1,
+            current_body_control: 0,
+            current_actions: vec![1, 9999, 2],
+        };
+        assert_eq!(
+            status.current_actions_labeled(),
+            [
+                (1, Some(Action::HandShake)),
+                (9999, None),
+                (2, Some(Action::HandWave)),
+            ]
+        );
+        assert_eq!(
+            status.current_actions_enum(),
+            [Action::HandShake, Action::HandWave]
+        );
+    }
+
+    #[test]
+    fn action_set_dedups_and_splits_unknown_codes() {
+        let status = GetStatusResponse {
+            current_mode: 1,
+            current_body_control: 0,
+            current_actions: vec![2, 1, 9999, 2, 9999],
+        };
+        assert_eq!(
+            status.action_set(),
+            HashSet::from([Action::HandShake, Action::HandWave])
+        );
+        assert_eq!(status.unknown_actions(), HashSet::from([9999]));
+        assert!(status.contains_action(Action::HandShake));
+        assert!(!status.contains_action(Action::DanceNezha));
+    }
+
+    #[test]
+    fn dexterous_finger_parameters_from_angles_number_fingers() {
+        let params = DexterousFingerParameter::from_angles([0, 100, 200, 300, 400, 500], 200, 800);
+        for (index, param) in params.iter().enumerate() {
+            assert_eq!(param.seq, index as i32);
+            assert_eq!(param.angle, index as i32 * 100);
+            assert_eq!((param.force, param.speed), (200, 800));
+        }
+    }
+}
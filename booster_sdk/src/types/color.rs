@@ -0,0 +1,150 @@
+//! Named colors shared by LED control and vision results.
+
+/// Coarse named colors with a representative RGB value each.
+///
+/// LED commands use [`Self::rgb`] to pick a color by name, and vision results
+/// use [`Self::classify`] to bucket a measured color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NamedColor {
+    Black,
+    White,
+    Gray,
+    Red,
+    Orange,
+    Amber,
+    Yellow,
+    Green,
+    Cyan,
+    Blue,
+    Purple,
+    Magenta,
+    Pink,
+    /// No usable color information.
+    Unknown,
+}
+
+impl NamedColor {
+    /// Look up a color by name, ignoring case and surrounding whitespace.
+    ///
+    /// Accepts every variant name except `unknown`, plus `off` as an alias
+    /// for [`Self::Black`].
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let color = match name.trim().to_ascii_lowercase().as_str() {
+            "off" | "black" => Self::Black,
+            "white" => Self::White,
+            "gray" => Self::Gray,
+            "red" => Self::Red,
+            "orange" => Self::Orange,
+            "amber" => Self::Amber,
+            "yellow" => Self::Yellow,
+            "green" => Self::Green,
+            "cyan" => Self::Cyan,
+            "blue" => Self::Blue,
+            "purple" => Self::Purple,
+            "magenta" => Self::Magenta,
+            "pink" => Self::Pink,
+            _ => return None,
+        };
+        Some(color)
+    }
+
+    /// Representative RGB value, or `None` for [`Self::Unknown`].
+    #[must_use]
+    pub fn rgb(self) -> Option<(u8, u8, u8)> {
+        let rgb = match self {
+            Self::Black => (0, 0, 0),
+            Self::White => (255, 255, 255),
+            Self::Gray => (128, 128, 128),
+            Self::Red => (255, 0, 0),
+            Self::Orange => (255, 128, 0),
+            Self::Amber => (255, 191, 0),
+            Self::Yellow => (255, 255, 0),
+            Self::Green => (0, 255, 0),
+            Self::Cyan => (0, 255, 255),
+            Self::Blue => (0, 0, 255),
+            Self::Purple => (128, 0, 128),
+            Self::Magenta => (255, 0, 255),
+            Self::Pink => (255, 105, 180),
+            Self::Unknown => return None,
+        };
+        Some(rgb)
+    }
+
+    /// Classify an RGB value by hue, falling back to black/white/gray for
+    /// dark or unsaturated colors.
+    #[must_use]
+    pub fn classify(r: u8, g: u8, b: u8) -> Self {
+        let (r, g, b) = (f32::from(r), f32::from(g), f32::from(b));
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let chroma = max - min;
+
+        if max < 50.0 {
+            return Self::Black;
+        }
+        if chroma / max < 0.2 {
+            return if max > 200.0 { Self::White } else { Self::Gray };
+        }
+
+        let hue = if max == r {
+            60.0 * ((g - b) / chroma).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / chroma + 2.0)
+        } else {
+            60.0 * ((r - g) / chroma + 4.0)
+        };
+
+        match hue {
+            h if h < 15.0 => Self::Red,
+            h if h < 38.0 => Self::Orange,
+            h if h < 52.0 => Self::Amber,
+            h if h < 70.0 => Self::Yellow,
+            h if h < 165.0 => Self::Green,
+            h if h < 195.0 => Self::Cyan,
+            h if h < 255.0 => Self::Blue,
+            h if h < 285.0 => Self::Purple,
+            // Purple and magenta share a hue; tell them apart by brightness.
+            h if h < 315.0 && max < 192.0 => Self::Purple,
+            h if h < 315.0 => Self::Magenta,
+            h if h < 345.0 => Self::Pink,
+            _ => Self::Red,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_buckets_primary_and_neutral_colors() {
+        assert_eq!(NamedColor::classify(220, 30, 40), NamedColor::Red);
+        assert_eq!(NamedColor::classify(250, 140, 20), NamedColor::Orange);
+        assert_eq!(NamedColor::classify(230, 220, 40), NamedColor::Yellow);
+        assert_eq!(NamedColor::classify(30, 180, 60), NamedColor::Green);
+        assert_eq!(NamedColor::classify(20, 60, 200), NamedColor::Blue);
+        assert_eq!(NamedColor::classify(10, 10, 12), NamedColor::Black);
+        assert_eq!(NamedColor::classify(240, 240, 235), NamedColor::White);
+        assert_eq!(NamedColor::classify(120, 125, 118), NamedColor::Gray);
+    }
+
+    #[test]
+    fn named_rgb_values_classify_as_themselves() {
+        for name in [
+            "black", "white", "gray", "red", "orange", "amber", "yellow", "green", "cyan", "blue",
+            "purple", "magenta", "pink",
+        ] {
+            let color = NamedColor::from_name(name).unwrap();
+            let (r, g, b) = color.rgb().unwrap();
+            assert_eq!(NamedColor::classify(r, g, b), color, "{name}");
+        }
+    }
+
+    #[test]
+    fn from_name_accepts_off_and_rejects_unknown() {
+        assert_eq!(NamedColor::from_name(" Off "), Some(NamedColor::Black));
+        assert_eq!(NamedColor::from_name("unknown"), None);
+        assert_eq!(NamedColor::Unknown.rgb(), None);
+    }
+}
@@ -20,9 +20,15 @@ pub enum BoosterError {
     #[error("State error: {0}")]
     State(#[from] StateError),
 
+    #[error("Custom trajectory error: {0}")]
+    CustomTraj(#[from] CustomTrajError),
+
     #[error("Validation error: {0}")]
     Validation(String),
 
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+
     #[error("Operation timed out after {timeout_ms}ms")]
     Timeout { timeout_ms: u64 },
 
@@ -33,6 +39,15 @@ pub enum BoosterError {
     Other(String),
 }
 
+impl BoosterError {
+    /// [`BoosterError::Timeout`] for `timeout`, saturating at `u64::MAX` ms.
+    pub(crate) fn timeout(timeout: Duration) -> Self {
+        Self::Timeout {
+            timeout_ms: u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX),
+        }
+    }
+}
+
 /// DDS-specific errors
 #[derive(Debug, Error)]
 #[non_exhaustive]
@@ -132,6 +147,9 @@ pub enum CommandError {
         current_mode: String,
     },
 
+    #[error("Robot did not reach mode {target} (current mode: {actual})")]
+    ModeNotReached { target: String, actual: String },
+
     #[error("Joint index {index} out of range (valid: 0-{max})")]
     InvalidJointIndex { index: usize, max: usize },
 
@@ -156,5 +174,142 @@ pub enum StateError {
     FrameNotFound(String),
 }
 
+/// Known failures of `LoadCustomTrainedTraj`, recognized from the error reply.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum CustomTrajError {
+    #[error("Trajectory file not found: {0}")]
+    FileNotFound(String),
+
+    #[error("Invalid trajectory parameters: {0}")]
+    InvalidParams(String),
+
+    #[error("Trajectory runner busy: {0}")]
+    Busy(String),
+}
+
+impl CustomTrajError {
+    /// Recognize a custom trajectory failure from an RPC error.
+    ///
+    /// Status 409 always maps to [`CustomTrajError::Busy`]; otherwise the
+    /// error message is matched against known phrases. Returns `None` when
+    /// the failure is not recognized.
+    #[must_use]
+    pub fn from_rpc(err: &RpcError) -> Option<Self> {
+        let message = match err {
+            RpcError::Conflict(message) => return Some(Self::Busy(message.clone())),
+            RpcError::BadRequest(message)
+            | RpcError::InternalServerError(message)
+            | RpcError::ServerRefused(message)
+            | RpcError::RequestTooFrequent(message)
+            | RpcError::StateTransitionFailed(message)
+            | RpcError::RequestFailed { message, .. } => message,
+            RpcError::Timeout { .. } | RpcError::InvalidStatusCode(_) => return None,
+        };
+
+        let lower = message.to_ascii_lowercase();
+        let has_any = |phrases: &[&str]| phrases.iter().any(|phrase| lower.contains(phrase));
+        if has_any(&["not found", "no such file", "not exist"]) {
+            Some(Self::FileNotFound(message.clone()))
+        } else if has_any(&["mismatch", "length", "invalid param"]) {
+            Some(Self::InvalidParams(message.clone()))
+        } else if has_any(&["busy", "already loaded", "in progress"]) {
+            Some(Self::Busy(message.clone()))
+        } else {
+            None
+        }
+    }
+}
+
+/// Integer that does not correspond to any variant of an SDK enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("Unknown {enum_name} value: {value}")]
+pub struct UnknownEnumValue {
+    pub enum_name: &'static str,
+    pub value: i32,
+}
+
+impl From<UnknownEnumValue> for BoosterError {
+    fn from(err: UnknownEnumValue) -> Self {
+        StateError::InvalidData(err.to_string()).into()
+    }
+}
+
+/// Name that does not correspond to any variant of an SDK enum.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Unknown {enum_name} name: {name}")]
+pub struct UnknownEnumName {
+    pub enum_name: &'static str,
+    pub name: String,
+}
+
+impl From<UnknownEnumName> for BoosterError {
+    fn from(err: UnknownEnumName) -> Self {
+        BoosterError::Validation(err.to_string())
+    }
+}
+
 /// Result type alias for Booster SDK operations
 pub type Result<T> = std::result::Result<T, BoosterError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_traj_error_maps_file_not_found() {
+        assert_eq!(
+            CustomTrajError::from_rpc(&RpcError::BadRequest(
+                "traj file /opt/a.bin does not exist".to_owned()
+            )),
+            Some(CustomTrajError::FileNotFound(
+                "traj file /opt/a.bin does not exist".to_owned()
+            ))
+        );
+        assert!(matches!(
+            CustomTrajError::from_rpc(&RpcError::InternalServerError(
+                "No such file or directory".to_owned()
+            )),
+            Some(CustomTrajError::FileNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn custom_traj_error_maps_invalid_params() {
+        assert!(matches!(
+            CustomTrajError::from_rpc(&RpcError::BadRequest(
+                "kp length mismatch: expected 23, got 22".to_owned()
+            )),
+            Some(CustomTrajError::InvalidParams(_))
+        ));
+    }
+
+    #[test]
+    fn custom_traj_error_maps_busy() {
+        assert!(matches!(
+            CustomTrajError::from_rpc(&RpcError::Conflict("anything".to_owned())),
+            Some(CustomTrajError::Busy(_))
+        ));
+        assert!(matches!(
+            CustomTrajError::from_rpc(&RpcError::RequestFailed {
+                status: 503,
+                message: "runner busy".to_owned(),
+            }),
+            Some(CustomTrajError::Busy(_))
+        ));
+    }
+
+    #[test]
+    fn custom_traj_error_leaves_unknown_failures_alone() {
+        assert_eq!(
+            CustomTrajError::from_rpc(&RpcError::InternalServerError("boom".to_owned())),
+            None
+        );
+        assert_eq!(
+            CustomTrajError::from_rpc(&RpcError::Timeout {
+                timeout: Duration::from_secs(1)
+            }),
+            None
+        );
+    }
+}
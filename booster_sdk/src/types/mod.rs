@@ -1,9 +1,11 @@
 //! Core domain types shared across the Booster Robotics SDK.
 
 mod b1;
+mod color;
 mod error;
 mod robot;
 
 pub use b1::*;
+pub use color::*;
 pub use error::*;
 pub use robot::*;
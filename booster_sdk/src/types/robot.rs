@@ -26,14 +26,34 @@ crate::api_id_enum! {
     }
 }
 
+impl RobotMode {
+    /// Whether the robot accepts locomotion commands in this mode.
+    #[must_use]
+    pub fn accepts_motion(self) -> bool {
+        matches!(self, Self::Walking | Self::Custom)
+    }
+}
+
 /// Hand selection (left or right)
+///
+/// Client methods take a `Hand` wherever the service expects a hand index;
+/// [`crate::types::HandIndex`] is only an alias for this type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[repr(usize)]
 pub enum Hand {
     Left = 0,
     Right = 1,
 }
 
+impl Hand {
+    /// Wire value sent as `hand_index`: 0 for left, 1 for right.
+    #[must_use]
+    pub fn index(self) -> i32 {
+        i32::from(self)
+    }
+}
+
 impl From<Hand> for usize {
     fn from(hand: Hand) -> Self {
         hand as usize
@@ -58,6 +78,24 @@ impl From<Hand> for u8 {
     }
 }
 
+impl std::str::FromStr for Hand {
+    type Err = super::UnknownEnumName;
+
+    /// Parse `left` or `right`, ignoring case.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        if name.eq_ignore_ascii_case("left") {
+            Ok(Hand::Left)
+        } else if name.eq_ignore_ascii_case("right") {
+            Ok(Hand::Right)
+        } else {
+            Err(super::UnknownEnumName {
+                enum_name: "Hand",
+                name: name.to_owned(),
+            })
+        }
+    }
+}
+
 impl TryFrom<usize> for Hand {
     type Error = ();
 
@@ -96,21 +134,68 @@ crate::api_id_enum! {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::UnknownEnumValue;
 
     #[test]
     fn test_robot_mode_conversion() {
         assert_eq!(RobotMode::try_from(-1), Ok(RobotMode::Unknown));
         assert_eq!(RobotMode::try_from(0), Ok(RobotMode::Damping));
         assert_eq!(RobotMode::try_from(2), Ok(RobotMode::Walking));
-        assert_eq!(RobotMode::try_from(99), Err("invalid value"));
+        assert_eq!(
+            RobotMode::try_from(99),
+            Err(UnknownEnumValue {
+                enum_name: "RobotMode",
+                value: 99,
+            })
+        );
 
         assert_eq!(i32::from(RobotMode::Walking), 2);
     }
 
+    #[test]
+    fn test_robot_mode_from_str() {
+        assert_eq!("walking".parse(), Ok(RobotMode::Walking));
+        assert_eq!("DAMPING".parse(), Ok(RobotMode::Damping));
+        assert_eq!(
+            "flying".parse::<RobotMode>().unwrap_err().to_string(),
+            "Unknown RobotMode name: flying"
+        );
+        for mode in RobotMode::ALL {
+            assert_eq!(format!("{mode:?}").parse(), Ok(*mode));
+        }
+    }
+
+    #[test]
+    fn test_robot_mode_accepts_motion() {
+        assert!(RobotMode::Walking.accepts_motion());
+        assert!(RobotMode::Custom.accepts_motion());
+        assert!(!RobotMode::Damping.accepts_motion());
+        assert!(!RobotMode::Prepare.accepts_motion());
+        assert!(!RobotMode::Soccer.accepts_motion());
+        assert!(!RobotMode::Unknown.accepts_motion());
+    }
+
     #[test]
     fn test_gripper_mode_conversion() {
         assert_eq!(GripperMode::try_from(0), Ok(GripperMode::Position));
         assert_eq!(GripperMode::try_from(1), Ok(GripperMode::Force));
-        assert_eq!(GripperMode::try_from(2), Err("invalid value"));
+        let err = GripperMode::try_from(2).unwrap_err();
+        assert_eq!(err.enum_name, "GripperMode");
+        assert_eq!(err.value, 2);
+        assert_eq!(err.to_string(), "Unknown GripperMode value: 2");
+    }
+
+    #[test]
+    fn hand_index_matches_wire_values() {
+        assert_eq!(Hand::Left.index(), 0);
+        assert_eq!(Hand::Right.index(), 1);
+        assert_eq!(Hand::try_from(Hand::Right.index()), Ok(Hand::Right));
+    }
+
+    #[test]
+    fn hand_from_str_ignores_case() {
+        assert_eq!("Left".parse(), Ok(Hand::Left));
+        assert_eq!("RIGHT".parse(), Ok(Hand::Right));
+        assert!("middle".parse::<Hand>().is_err());
     }
 }
@@ -5,6 +5,7 @@ from __future__ import annotations
 import booster_sdk_bindings as bindings
 
 AiClient = bindings.AiClient
+SubscriptionHandle = bindings.SubscriptionHandle
 BoosterSdkError = bindings.BoosterSdkError
 BOOSTER_ROBOT_USER_ID = bindings.BOOSTER_ROBOT_USER_ID
 TtsConfig = bindings.TtsConfig
@@ -15,6 +16,7 @@ SpeakParameter = bindings.SpeakParameter
 
 __all__ = [
     "AiClient",
+    "SubscriptionHandle",
     "BoosterSdkError",
     "BOOSTER_ROBOT_USER_ID",
     "TtsConfig",
@@ -5,12 +5,14 @@ from __future__ import annotations
 import booster_sdk_bindings as bindings
 
 LuiClient = bindings.LuiClient
+SubscriptionHandle = bindings.SubscriptionHandle
 BoosterSdkError = bindings.BoosterSdkError
 LuiTtsConfig = bindings.LuiTtsConfig
 LuiTtsParameter = bindings.LuiTtsParameter
 
 __all__ = [
     "LuiClient",
+    "SubscriptionHandle",
     "BoosterSdkError",
     "LuiTtsConfig",
     "LuiTtsParameter",
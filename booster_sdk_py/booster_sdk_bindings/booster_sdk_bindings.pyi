@@ -2,6 +2,9 @@
 
 from __future__ import annotations
 
+from collections.abc import Callable
+from typing import Any
+
 BOOSTER_ROBOT_USER_ID: str
 
 class BoosterSdkError(Exception):
@@ -728,6 +731,18 @@ class GetStatusResponse:
         """Active actions converted to known ``Action`` values."""
         ...
 
+    def current_actions_labeled(self) -> list[tuple[int, Action | None]]:
+        """Every active action code paired with its ``Action``, or ``None``."""
+        ...
+
+    def contains_action(self, action: Action) -> bool:
+        """Whether ``action`` is currently running."""
+        ...
+
+    def unknown_actions(self) -> list[int]:
+        """Sorted, deduplicated action codes with no known ``Action``."""
+        ...
+
 class GetRobotInfoResponse:
     """Robot identity and firmware metadata response."""
 
@@ -853,7 +868,12 @@ class DetectResults:
         ...
 
 class BoosterClient:
-    """High-level locomotion and body-control client for the robot."""
+    """High-level locomotion and body-control client for the robot.
+
+    Methods that accept a keyword-only ``timeout`` use it, in seconds, for the
+    RPC call instead of the client default. ``None`` keeps the default; a
+    non-positive or non-finite value raises ``ValueError``.
+    """
 
     def __init__(self, startup_wait_sec: float | None = ...) -> None:
         """Create a client.
@@ -864,23 +884,54 @@ class BoosterClient:
         """
         ...
 
-    def change_mode(self, mode: RobotMode) -> None:
-        """Request robot mode transition."""
+    @property
+    def default_timeout(self) -> float:
+        """RPC timeout in seconds used when a call passes no ``timeout``.
+
+        Assigning a new value affects subsequent calls and raises
+        ``ValueError`` unless it is a positive finite number.
+        """
         ...
 
-    def get_mode(self) -> GetModeResponse:
+    @default_timeout.setter
+    def default_timeout(self, seconds: float) -> None: ...
+
+    def change_mode(self, mode: RobotMode, *, timeout: float | None = None) -> None:
+        """Request robot mode transition.
+
+        Waits at least 10 seconds for the reply; a shorter ``timeout`` is
+        raised to that minimum.
+        """
+        ...
+
+    def get_mode(self, *, timeout: float | None = None) -> GetModeResponse:
         """Fetch current robot mode."""
         ...
 
-    def get_status(self) -> GetStatusResponse:
+    def is_ready(self) -> bool:
+        """Return whether the robot is reachable and in a mode that accepts motion.
+
+        Returns:
+            ``True`` only in walking or custom mode.
+
+        Raises:
+            BoosterSdkError: If the service cannot be reached.
+        """
+        ...
+
+    def get_status(self, *, timeout: float | None = None) -> GetStatusResponse:
         """Fetch current robot status summary."""
         ...
 
-    def get_robot_info(self) -> GetRobotInfoResponse:
+    def get_robot_info(
+        self, *, timeout: float | None = None
+    ) -> GetRobotInfoResponse:
         """Fetch robot identity/version metadata."""
         ...
 
-    def move_robot(self, vx: float, vy: float, vyaw: float) -> None:
+    def move_robot(
+        self, vx: float, vy: float, vyaw: float, *, timeout: float | None = None
+    ) -> None:
         """Command base motion in body frame."""
         ...
 
@@ -888,6 +939,14 @@ class BoosterClient:
         """Rotate head to target pitch/yaw angles."""
         ...
 
+    def center_head(self) -> None:
+        """Rotate head back to looking straight ahead."""
+        ...
+
+    def look_at_degrees(self, pitch_deg: float, yaw_deg: float) -> None:
+        """Rotate head to target pitch/yaw angles given in degrees."""
+        ...
+
     def wave_hand(self, action: HandAction) -> None:
         """Trigger hand wave/open-close style action."""
         ...
@@ -898,18 +957,36 @@ class BoosterClient:
         """Rotate head using directional step commands."""
         ...
 
-    def lie_down(self) -> None:
+    def lie_down(self, *, timeout: float | None = None) -> None:
         """Command robot to lie down."""
         ...
 
-    def get_up(self) -> None:
+    def get_up(self, *, timeout: float | None = None) -> None:
         """Command robot to stand up."""
         ...
 
-    def get_up_with_mode(self, mode: RobotMode) -> None:
+    def get_up_with_mode(
+        self, mode: RobotMode, *, timeout: float | None = None
+    ) -> None:
         """Stand up and transition into specified mode."""
         ...
 
+    def stand_up_into(self, mode: RobotMode, timeout: float) -> None:
+        """Stand up into `mode` and wait until the robot reports it.
+
+        Raises:
+            BoosterSdkError: If the mode is not reached within `timeout` seconds.
+        """
+        ...
+
+    def wait_for_mode(self, mode: RobotMode, timeout: float) -> None:
+        """Wait until the robot reports `mode` as its current mode.
+
+        Raises:
+            BoosterSdkError: If the mode is not reached within `timeout` seconds.
+        """
+        ...
+
     def shoot(self) -> None:
         """Trigger shoot action."""
         ...
@@ -980,6 +1057,31 @@ class BoosterClient:
         """Send low-level dexterous-hand finger commands."""
         ...
 
+    def control_dexterous_hand_from_angles(
+        self,
+        angles: list[float],
+        force: int,
+        speed: int,
+        hand_index: Hand,
+        hand_type: BoosterHandType,
+    ) -> None:
+        """Command all six fingers of a dexterous hand from a list of angles.
+
+        Builds the per-finger parameters with ``seq`` 0 to 5 in list order.
+
+        Args:
+            angles: Six target angles in hand units; rounded to integers.
+            force: Force limit applied to every finger.
+            speed: Speed applied to every finger.
+            hand_index: Hand to control.
+            hand_type: Hand hardware type.
+
+        Raises:
+            ValueError: If ``angles`` does not have six finite entries.
+            BoosterSdkError: If the service returns an error status.
+        """
+        ...
+
     def control_dexterous_hand_default(
         self,
         finger_params: list[DexterousFingerParameter],
@@ -993,7 +1095,11 @@ class BoosterClient:
         ...
 
     def play_sound(self, sound_file_path: str) -> None:
-        """Play a sound file on robot audio output."""
+        """Play a sound file on robot audio output.
+
+        Args:
+            sound_file_path: Absolute path of the file on the robot.
+        """
         ...
 
     def stop_sound(self) -> None:
@@ -1009,7 +1115,11 @@ class BoosterClient:
         ...
 
     def replay_trajectory(self, traj_file_path: str) -> None:
-        """Replay a recorded trajectory from path."""
+        """Replay a recorded trajectory from path.
+
+        Args:
+            traj_file_path: Absolute path of the file on the robot.
+        """
         ...
 
     def whole_body_dance(self, dance_id: WholeBodyDanceId) -> None:
@@ -1060,6 +1170,28 @@ class BoosterClient:
         """Start or stop a visual kick (side-foot kick)."""
         ...
 
+    def send_loco_request(
+        self, api_id: int, body: str, *, timeout: float | None = None
+    ) -> str:
+        """Send a raw JSON request to the locomotion service.
+
+        Escape hatch for API ids that do not have a dedicated method yet.
+
+        Args:
+            api_id: Raw locomotion API id.
+            body: JSON request body, or an empty string for no parameters.
+            timeout: Optional RPC timeout in seconds overriding the default.
+
+        Returns:
+            The raw JSON response body.
+
+        Raises:
+            ValueError: If ``timeout`` is not a positive finite number.
+            BoosterSdkError: If ``body`` is not valid JSON or the service
+                returns an error status.
+        """
+        ...
+
     def publish_gripper_command(self, command: GripperCommand) -> None:
         """Publish low-level gripper command message."""
         ...
@@ -1074,6 +1206,19 @@ class BoosterClient:
         """Convenience wrapper for publishing gripper command fields."""
         ...
 
+class SubscriptionHandle:
+    """Handle for a background topic subscription feeding a Python callback."""
+
+    def stop(self) -> None:
+        """Stop delivering messages. Safe to call more than once."""
+        ...
+    def is_active(self) -> bool:
+        """Return ``True`` while messages are still being delivered."""
+        ...
+    def __repr__(self) -> str:
+        """Return a short state representation."""
+        ...
+
 class AiClient:
     """Client for AI chat and speech features."""
 
@@ -1105,6 +1250,20 @@ class AiClient:
         """Disable AI face tracking mode."""
         ...
 
+    def subtitles(
+        self, callback: Callable[[dict[str, Any]], object]
+    ) -> SubscriptionHandle:
+        """Deliver AI subtitle messages to ``callback`` in the background.
+
+        Each message is passed as a ``dict`` with the keys ``magic_number``,
+        ``text``, ``language``, ``user_id``, ``seq``, ``definite``,
+        ``paragraph`` and ``round_id``. The callback runs on a background
+        thread; exceptions it raises are printed and do not stop delivery.
+
+        Keep a reference to the returned handle; dropping it stops delivery.
+        """
+        ...
+
 class LuiClient:
     """Client for LUI ASR/TTS APIs."""
 
@@ -1136,6 +1295,19 @@ class LuiClient:
         """Send text payload for TTS synthesis."""
         ...
 
+    def asr_chunks(
+        self, callback: Callable[[dict[str, Any]], object]
+    ) -> SubscriptionHandle:
+        """Deliver ASR chunk messages to ``callback`` in the background.
+
+        Each chunk is passed as a ``dict`` with a single ``text`` key. The
+        callback runs on a background thread; exceptions it raises are printed
+        and do not stop delivery.
+
+        Keep a reference to the returned handle; dropping it stops delivery.
+        """
+        ...
+
 class LightControlClient:
     """Client for LED light control APIs."""
 
@@ -1151,10 +1323,43 @@ class LightControlClient:
         """Set LED strip color using RGB values (0-255)."""
         ...
 
+    def set_led_light_color_hex(self, color: str) -> None:
+        """Set LED strip color from a hex string.
+
+        Args:
+            color: Color in ``#RRGGBB`` form, e.g. ``"#ff8800"``.
+
+        Raises:
+            ValueError: If ``color`` is not a valid ``#RRGGBB`` string.
+        """
+        ...
+
+    def set_led_light_color_named(self, name: str) -> None:
+        """Set LED strip color by name.
+
+        Args:
+            name: Case-insensitive color name: ``off`` (or ``black``),
+                ``white``, ``gray``, ``red``, ``orange``, ``amber``,
+                ``yellow``, ``green``, ``cyan``, ``blue``, ``purple``,
+                ``magenta`` or ``pink``.
+
+        Raises:
+            ValueError: If ``name`` is not a known color.
+        """
+        ...
+
     def stop_led_light_control(self) -> None:
         """Stop active LED control program/effect."""
         ...
 
+    def last_color(self) -> tuple[int, int, int] | None:
+        """Last ``(r, g, b)`` color set through this client.
+
+        Tracked client-side because the robot cannot report the LED state.
+        ``None`` before the first set or after ``stop_led_light_control``.
+        """
+        ...
+
 class VisionClient:
     """Client for vision inference APIs."""
 
@@ -1204,6 +1409,20 @@ class X5CameraClient:
         """Change camera mode."""
         ...
 
+    def change_mode_confirmed(self, mode: CameraSetMode, timeout_sec: float) -> None:
+        """Change camera mode and wait until the camera reports it.
+
+        Args:
+            mode: Requested camera mode.
+            timeout_sec: Maximum time to wait for the new status, in seconds.
+
+        Raises:
+            ValueError: If ``timeout_sec`` is not a positive number.
+            BoosterSdkError: If the camera reports an error or the mode is not
+                reached in time.
+        """
+        ...
+
     def get_status(self) -> X5CameraGetStatusResponse:
         """Get current camera status."""
         ...
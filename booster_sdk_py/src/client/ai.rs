@@ -1,11 +1,20 @@
 use std::sync::Arc;
 
 use booster_sdk::client::ai::{
-    AiClient, AsrConfig, LlmConfig, SpeakParameter, StartAiChatParameter, TtsConfig,
+    AiClient, AsrConfig, LlmConfig, SpeakParameter, StartAiChatParameter, Subtitle, TtsConfig,
+};
+use pyo3::{
+    Bound,
+    prelude::*,
+    types::{PyDict, PyModule},
 };
-use pyo3::{Bound, prelude::*, types::PyModule};
 
-use crate::{runtime::wait_for_future, startup_wait_from_seconds, to_py_err};
+use crate::{
+    runtime::wait_for_future,
+    startup_wait_from_seconds,
+    subscription::{PySubscriptionHandle, spawn_callback_stream},
+    to_py_err,
+};
 
 #[pyclass(module = "booster_sdk_bindings", name = "TtsConfig")]
 #[derive(Clone)]
@@ -232,6 +241,24 @@ impl PyAiClient {
         let client = Arc::clone(&self.client);
         wait_for_future(py, async move { client.stop_face_tracking().await }).map_err(to_py_err)
     }
+
+    fn subtitles(&self, py: Python<'_>, callback: Py<PyAny>) -> PyResult<PySubscriptionHandle> {
+        let subscription = self.client.subscribe_subtitle().map_err(to_py_err)?;
+        spawn_callback_stream(py, subscription, callback, subtitle_to_dict)
+    }
+}
+
+fn subtitle_to_dict<'py>(py: Python<'py>, subtitle: &Subtitle) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("magic_number", &subtitle.magic_number)?;
+    dict.set_item("text", &subtitle.text)?;
+    dict.set_item("language", &subtitle.language)?;
+    dict.set_item("user_id", &subtitle.user_id)?;
+    dict.set_item("seq", subtitle.seq)?;
+    dict.set_item("definite", subtitle.definite)?;
+    dict.set_item("paragraph", subtitle.paragraph)?;
+    dict.set_item("round_id", subtitle.round_id)?;
+    Ok(dict)
 }
 
 pub(crate) fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
@@ -10,9 +10,12 @@ use booster_sdk::{
         Quaternion, RobotMode, Transform, WholeBodyDanceId,
     },
 };
-use pyo3::{Bound, prelude::*, types::PyModule};
+use pyo3::{Bound, exceptions::PyValueError, prelude::*, types::PyModule};
 
-use crate::{runtime::wait_for_future, startup_wait_from_seconds, to_py_err};
+use crate::{
+    runtime::wait_for_future, startup_wait_from_seconds, timeout_from_seconds, to_py_err,
+    with_timeout_sec,
+};
 
 #[pyclass(module = "booster_sdk_bindings", name = "RobotMode", eq)]
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -1154,6 +1157,24 @@ impl PyGetStatusResponse {
             .collect()
     }
 
+    fn current_actions_labeled(&self) -> Vec<(i32, Option<PyAction>)> {
+        self.0
+            .current_actions_labeled()
+            .into_iter()
+            .map(|(code, action)| (code, action.map(Into::into)))
+            .collect()
+    }
+
+    fn contains_action(&self, action: PyAction) -> bool {
+        self.0.contains_action(action.0)
+    }
+
+    fn unknown_actions(&self) -> Vec<i32> {
+        let mut codes: Vec<i32> = self.0.unknown_actions().into_iter().collect();
+        codes.sort_unstable();
+        codes
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "GetStatusResponse(current_mode={}, current_body_control={}, current_actions={:?})",
@@ -1282,35 +1303,79 @@ impl PyBoosterClient {
         })
     }
 
-    fn change_mode(&self, py: Python<'_>, mode: PyRobotMode) -> PyResult<()> {
+    #[getter]
+    fn default_timeout(&self) -> f64 {
+        self.client.rpc().default_timeout().as_secs_f64()
+    }
+
+    #[setter]
+    fn set_default_timeout(&self, seconds: f64) -> PyResult<()> {
+        let timeout = timeout_from_seconds("default_timeout", seconds)?;
+        self.client.rpc().set_default_timeout(timeout);
+        Ok(())
+    }
+
+    #[pyo3(signature = (mode, *, timeout=None))]
+    fn change_mode(&self, py: Python<'_>, mode: PyRobotMode, timeout: Option<f64>) -> PyResult<()> {
         let client = Arc::clone(&self.client);
-        wait_for_future(py, async move { client.change_mode(mode.into()).await }).map_err(to_py_err)
+        let future = with_timeout_sec(
+            timeout,
+            async move { client.change_mode(mode.into()).await },
+        )?;
+        wait_for_future(py, future).map_err(to_py_err)
     }
 
-    fn get_mode(&self, py: Python<'_>) -> PyResult<PyGetModeResponse> {
+    #[pyo3(signature = (*, timeout=None))]
+    fn get_mode(&self, py: Python<'_>, timeout: Option<f64>) -> PyResult<PyGetModeResponse> {
         let client = Arc::clone(&self.client);
-        wait_for_future(py, async move { client.get_mode().await })
+        let future = with_timeout_sec(timeout, async move { client.get_mode().await })?;
+        wait_for_future(py, future)
             .map(Into::into)
             .map_err(to_py_err)
     }
 
-    fn get_status(&self, py: Python<'_>) -> PyResult<PyGetStatusResponse> {
+    fn is_ready(&self, py: Python<'_>) -> PyResult<bool> {
+        let client = Arc::clone(&self.client);
+        wait_for_future(py, async move { client.is_ready().await }).map_err(to_py_err)
+    }
+
+    #[pyo3(signature = (*, timeout=None))]
+    fn get_status(&self, py: Python<'_>, timeout: Option<f64>) -> PyResult<PyGetStatusResponse> {
         let client = Arc::clone(&self.client);
-        wait_for_future(py, async move { client.get_status().await })
+        let future = with_timeout_sec(timeout, async move { client.get_status().await })?;
+        wait_for_future(py, future)
             .map(Into::into)
             .map_err(to_py_err)
     }
 
-    fn get_robot_info(&self, py: Python<'_>) -> PyResult<PyGetRobotInfoResponse> {
+    #[pyo3(signature = (*, timeout=None))]
+    fn get_robot_info(
+        &self,
+        py: Python<'_>,
+        timeout: Option<f64>,
+    ) -> PyResult<PyGetRobotInfoResponse> {
         let client = Arc::clone(&self.client);
-        wait_for_future(py, async move { client.get_robot_info().await })
+        let future = with_timeout_sec(timeout, async move { client.get_robot_info().await })?;
+        wait_for_future(py, future)
             .map(Into::into)
             .map_err(to_py_err)
     }
 
-    fn move_robot(&self, py: Python<'_>, vx: f32, vy: f32, vyaw: f32) -> PyResult<()> {
+    #[pyo3(signature = (vx, vy, vyaw, *, timeout=None))]
+    fn move_robot(
+        &self,
+        py: Python<'_>,
+        vx: f32,
+        vy: f32,
+        vyaw: f32,
+        timeout: Option<f64>,
+    ) -> PyResult<()> {
         let client = Arc::clone(&self.client);
-        wait_for_future(py, async move { client.move_robot(vx, vy, vyaw).await }).map_err(to_py_err)
+        let future = with_timeout_sec(
+            timeout,
+            async move { client.move_robot(vx, vy, vyaw).await },
+        )?;
+        wait_for_future(py, future).map_err(to_py_err)
     }
 
     fn rotate_head(&self, py: Python<'_>, pitch: f32, yaw: f32) -> PyResult<()> {
@@ -1318,6 +1383,19 @@ impl PyBoosterClient {
         wait_for_future(py, async move { client.rotate_head(pitch, yaw).await }).map_err(to_py_err)
     }
 
+    fn center_head(&self, py: Python<'_>) -> PyResult<()> {
+        let client = Arc::clone(&self.client);
+        wait_for_future(py, async move { client.center_head().await }).map_err(to_py_err)
+    }
+
+    fn look_at_degrees(&self, py: Python<'_>, pitch_deg: f32, yaw_deg: f32) -> PyResult<()> {
+        let client = Arc::clone(&self.client);
+        wait_for_future(py, async move {
+            client.look_at_degrees(pitch_deg, yaw_deg).await
+        })
+        .map_err(to_py_err)
+    }
+
     fn wave_hand(&self, py: Python<'_>, action: PyHandAction) -> PyResult<()> {
         let client = Arc::clone(&self.client);
         wait_for_future(py, async move { client.wave_hand(action.into()).await }).map_err(to_py_err)
@@ -1338,22 +1416,49 @@ impl PyBoosterClient {
         .map_err(to_py_err)
     }
 
-    fn lie_down(&self, py: Python<'_>) -> PyResult<()> {
+    #[pyo3(signature = (*, timeout=None))]
+    fn lie_down(&self, py: Python<'_>, timeout: Option<f64>) -> PyResult<()> {
         let client = Arc::clone(&self.client);
-        wait_for_future(py, async move { client.lie_down().await }).map_err(to_py_err)
+        let future = with_timeout_sec(timeout, async move { client.lie_down().await })?;
+        wait_for_future(py, future).map_err(to_py_err)
     }
 
-    fn get_up(&self, py: Python<'_>) -> PyResult<()> {
+    #[pyo3(signature = (*, timeout=None))]
+    fn get_up(&self, py: Python<'_>, timeout: Option<f64>) -> PyResult<()> {
         let client = Arc::clone(&self.client);
-        wait_for_future(py, async move { client.get_up().await }).map_err(to_py_err)
+        let future = with_timeout_sec(timeout, async move { client.get_up().await })?;
+        wait_for_future(py, future).map_err(to_py_err)
     }
 
-    fn get_up_with_mode(&self, py: Python<'_>, mode: PyRobotMode) -> PyResult<()> {
+    #[pyo3(signature = (mode, *, timeout=None))]
+    fn get_up_with_mode(
+        &self,
+        py: Python<'_>,
+        mode: PyRobotMode,
+        timeout: Option<f64>,
+    ) -> PyResult<()> {
         let client = Arc::clone(&self.client);
-        wait_for_future(
-            py,
-            async move { client.get_up_with_mode(mode.into()).await },
-        )
+        let future = with_timeout_sec(timeout, async move {
+            client.get_up_with_mode(mode.into()).await
+        })?;
+        wait_for_future(py, future).map_err(to_py_err)
+    }
+
+    fn stand_up_into(&self, py: Python<'_>, mode: PyRobotMode, timeout: f64) -> PyResult<()> {
+        let timeout = timeout_from_seconds("timeout", timeout)?;
+        let client = Arc::clone(&self.client);
+        wait_for_future(py, async move {
+            client.stand_up_into(mode.into(), timeout).await
+        })
+        .map_err(to_py_err)
+    }
+
+    fn wait_for_mode(&self, py: Python<'_>, mode: PyRobotMode, timeout: f64) -> PyResult<()> {
+        let timeout = timeout_from_seconds("timeout", timeout)?;
+        let client = Arc::clone(&self.client);
+        wait_for_future(py, async move {
+            client.wait_for_mode(mode.into(), timeout).await
+        })
         .map_err(to_py_err)
     }
 
@@ -1506,6 +1611,44 @@ impl PyBoosterClient {
         .map_err(to_py_err)
     }
 
+    fn control_dexterous_hand_from_angles(
+        &self,
+        py: Python<'_>,
+        angles: Vec<f64>,
+        force: i32,
+        speed: i32,
+        hand_index: PyHand,
+        hand_type: PyBoosterHandType,
+    ) -> PyResult<()> {
+        let angles: [f64; DexterousFingerParameter::FINGER_COUNT] =
+            angles.try_into().map_err(|angles: Vec<f64>| {
+                PyValueError::new_err(format!(
+                    "angles must have {} entries, got {}",
+                    DexterousFingerParameter::FINGER_COUNT,
+                    angles.len()
+                ))
+            })?;
+        if let Some(angle) = angles.iter().find(|angle| !angle.is_finite()) {
+            return Err(PyValueError::new_err(format!(
+                "angles must be finite, got {angle}"
+            )));
+        }
+        let finger_params = DexterousFingerParameter::from_angles(
+            angles.map(|angle| angle.round() as i32),
+            force,
+            speed,
+        );
+        let client = Arc::clone(&self.client);
+        let hand_index: Hand = hand_index.into();
+        let hand_type: BoosterHandType = hand_type.into();
+        wait_for_future(py, async move {
+            client
+                .control_dexterous_hand(&finger_params, hand_index, hand_type)
+                .await
+        })
+        .map_err(to_py_err)
+    }
+
     fn control_dexterous_hand_default(
         &self,
         py: Python<'_>,
@@ -1653,6 +1796,19 @@ impl PyBoosterClient {
         wait_for_future(py, async move { client.visual_kick(start).await }).map_err(to_py_err)
     }
 
+    #[pyo3(signature = (api_id, body, *, timeout=None))]
+    fn send_loco_request(
+        &self,
+        py: Python<'_>,
+        api_id: i32,
+        body: String,
+        timeout: Option<f64>,
+    ) -> PyResult<String> {
+        let client = Arc::clone(&self.client);
+        let future = with_timeout_sec(timeout, async move { client.call_raw(api_id, body).await })?;
+        wait_for_future(py, future).map_err(to_py_err)
+    }
+
     fn publish_gripper_command(&self, command: PyGripperCommand) -> PyResult<()> {
         let command: GripperCommand = command.into();
         self.client
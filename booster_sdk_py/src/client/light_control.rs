@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
-use booster_sdk::client::light_control::LightControlClient;
-use pyo3::{Bound, prelude::*, types::PyModule};
+use booster_sdk::client::light_control::{LightControlClient, SetLedLightColorParameter};
+use pyo3::{Bound, exceptions::PyValueError, prelude::*, types::PyModule};
 
 use crate::{runtime::wait_for_future, startup_wait_from_seconds, to_py_err};
 
@@ -37,10 +37,39 @@ impl PyLightControlClient {
             .map_err(to_py_err)
     }
 
+    fn set_led_light_color_hex(&self, py: Python<'_>, color: &str) -> PyResult<()> {
+        let param = SetLedLightColorParameter::from_hex(color).ok_or_else(|| {
+            PyValueError::new_err(format!("invalid color {color:?}, expected '#RRGGBB'"))
+        })?;
+        let client = Arc::clone(&self.client);
+        wait_for_future(
+            py,
+            async move { client.set_led_light_color_param(&param).await },
+        )
+        .map_err(to_py_err)
+    }
+
+    fn set_led_light_color_named(&self, py: Python<'_>, name: &str) -> PyResult<()> {
+        let param = SetLedLightColorParameter::from_name(name)
+            .ok_or_else(|| PyValueError::new_err(format!("unknown color name {name:?}")))?;
+        let client = Arc::clone(&self.client);
+        wait_for_future(
+            py,
+            async move { client.set_led_light_color_param(&param).await },
+        )
+        .map_err(to_py_err)
+    }
+
     fn stop_led_light_control(&self, py: Python<'_>) -> PyResult<()> {
         let client = Arc::clone(&self.client);
         wait_for_future(py, async move { client.stop_led_light_control().await }).map_err(to_py_err)
     }
+
+    fn last_color(&self) -> Option<(u8, u8, u8)> {
+        self.client
+            .last_color()
+            .map(|color| (color.r, color.g, color.b))
+    }
 }
 
 pub(crate) fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
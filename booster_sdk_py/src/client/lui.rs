@@ -1,9 +1,18 @@
 use std::sync::Arc;
 
-use booster_sdk::client::ai::{LuiClient, LuiTtsConfig, LuiTtsParameter};
-use pyo3::{Bound, prelude::*, types::PyModule};
-
-use crate::{runtime::wait_for_future, startup_wait_from_seconds, to_py_err};
+use booster_sdk::client::ai::{AsrChunk, LuiClient, LuiTtsConfig, LuiTtsParameter};
+use pyo3::{
+    Bound,
+    prelude::*,
+    types::{PyDict, PyModule},
+};
+
+use crate::{
+    runtime::wait_for_future,
+    startup_wait_from_seconds,
+    subscription::{PySubscriptionHandle, spawn_callback_stream},
+    to_py_err,
+};
 
 #[pyclass(module = "booster_sdk_bindings", name = "LuiTtsConfig")]
 #[derive(Clone)]
@@ -99,6 +108,17 @@ impl PyLuiClient {
         let param = param.into();
         wait_for_future(py, async move { client.send_tts_text(&param).await }).map_err(to_py_err)
     }
+
+    fn asr_chunks(&self, py: Python<'_>, callback: Py<PyAny>) -> PyResult<PySubscriptionHandle> {
+        let subscription = self.client.subscribe_asr_chunk().map_err(to_py_err)?;
+        spawn_callback_stream(py, subscription, callback, asr_chunk_to_dict)
+    }
+}
+
+fn asr_chunk_to_dict<'py>(py: Python<'py>, chunk: &AsrChunk) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("text", &chunk.text)?;
+    Ok(dict)
 }
 
 pub(crate) fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
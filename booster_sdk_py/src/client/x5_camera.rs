@@ -5,7 +5,7 @@ use booster_sdk::client::x5_camera::{
 };
 use pyo3::{Bound, prelude::*, types::PyModule};
 
-use crate::{runtime::wait_for_future, startup_wait_from_seconds, to_py_err};
+use crate::{runtime::wait_for_future, startup_wait_from_seconds, timeout_from_seconds, to_py_err};
 
 #[pyclass(module = "booster_sdk_bindings", name = "CameraSetMode", eq)]
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -151,6 +151,20 @@ impl PyX5CameraClient {
         wait_for_future(py, async move { client.change_mode(mode.into()).await }).map_err(to_py_err)
     }
 
+    fn change_mode_confirmed(
+        &self,
+        py: Python<'_>,
+        mode: PyCameraSetMode,
+        timeout_sec: f64,
+    ) -> PyResult<()> {
+        let timeout = timeout_from_seconds("timeout_sec", timeout_sec)?;
+        let client = Arc::clone(&self.client);
+        wait_for_future(py, async move {
+            client.change_mode_confirmed(mode.into(), timeout).await
+        })
+        .map_err(to_py_err)
+    }
+
     fn get_status(&self, py: Python<'_>) -> PyResult<PyX5CameraGetStatusResponse> {
         let client = Arc::clone(&self.client);
         wait_for_future(py, async move { client.get_status().await })
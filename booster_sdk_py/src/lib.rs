@@ -1,5 +1,6 @@
 mod client;
 mod runtime;
+mod subscription;
 
 use std::sync::OnceLock;
 use std::time::Duration;
@@ -35,6 +36,36 @@ pub(crate) fn startup_wait_from_seconds(
     Ok(Some(Duration::from_secs_f64(seconds)))
 }
 
+/// Run `future` under an optional per-call RPC timeout given in seconds.
+pub(crate) fn with_timeout_sec<F>(
+    timeout: Option<f64>,
+    future: F,
+) -> PyResult<impl Future<Output = F::Output> + Send + 'static>
+where
+    F: Future + Send + 'static,
+{
+    let timeout = timeout
+        .map(|seconds| timeout_from_seconds("timeout", seconds))
+        .transpose()?;
+    Ok(async move {
+        match timeout {
+            Some(timeout) => booster_sdk::dds::with_call_timeout(timeout, future).await,
+            None => future.await,
+        }
+    })
+}
+
+pub(crate) fn timeout_from_seconds(name: &str, seconds: f64) -> PyResult<Duration> {
+    if !seconds.is_finite() {
+        return Err(PyValueError::new_err(format!("{name} must be finite")));
+    }
+    if seconds <= 0.0 {
+        return Err(PyValueError::new_err(format!("{name} must be > 0")));
+    }
+
+    Ok(Duration::from_secs_f64(seconds))
+}
+
 fn rpc_debug_enabled() -> bool {
     std::env::var("BOOSTER_RPC_DEBUG")
         .map(|value| {
@@ -74,6 +105,7 @@ fn booster_sdk_bindings(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()>
     m.add("BOOSTER_ROBOT_USER_ID", BOOSTER_ROBOT_USER_ID)?;
 
     client::register_classes(m)?;
+    subscription::register(m)?;
 
     Ok(())
 }
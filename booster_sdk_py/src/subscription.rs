@@ -0,0 +1,63 @@
+use booster_sdk::{dds::DdsSubscription, task::TaskHandle};
+use pyo3::{Bound, exceptions::PyTypeError, prelude::*, types::PyDict, types::PyModule};
+
+use crate::runtime::get_tokio_runtime;
+
+/// Handle for a background subscription that delivers samples to a Python callback.
+///
+/// Delivery stops when `stop()` is called or the handle is dropped.
+#[pyclass(module = "booster_sdk_bindings", name = "SubscriptionHandle")]
+pub struct PySubscriptionHandle {
+    task: TaskHandle,
+}
+
+#[pymethods]
+impl PySubscriptionHandle {
+    fn stop(&mut self) {
+        self.task.stop();
+    }
+
+    fn is_active(&self) -> bool {
+        self.task.is_active()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SubscriptionHandle(active={})", self.is_active())
+    }
+}
+
+/// Forward every sample from `subscription` to `callback` as a `dict` built by `to_dict`.
+///
+/// Exceptions raised by the callback are printed and do not stop the stream.
+pub(crate) fn spawn_callback_stream<T, F>(
+    py: Python<'_>,
+    mut subscription: DdsSubscription<T>,
+    callback: Py<PyAny>,
+    to_dict: F,
+) -> PyResult<PySubscriptionHandle>
+where
+    T: Send + 'static,
+    F: for<'py> Fn(Python<'py>, &T) -> PyResult<Bound<'py, PyDict>> + Send + 'static,
+{
+    if !callback.bind(py).is_callable() {
+        return Err(PyTypeError::new_err("callback must be callable"));
+    }
+
+    let task = TaskHandle::spawn_on(get_tokio_runtime().handle(), async move {
+        while let Some(sample) = subscription.recv().await {
+            Python::attach(|py| {
+                let result = to_dict(py, &sample).and_then(|dict| callback.call1(py, (dict,)));
+                if let Err(err) = result {
+                    err.print(py);
+                }
+            });
+        }
+    });
+
+    Ok(PySubscriptionHandle { task })
+}
+
+pub(crate) fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PySubscriptionHandle>()?;
+    Ok(())
+}
@@ -23,7 +23,6 @@ def main() -> None:
         (0.0, 0.7),
         (0.2, -0.4),
         (-0.2, 0.4),
-        (0.0, 0.0),
     ]
 
     for pitch, yaw in positions:
@@ -31,6 +30,9 @@ def main() -> None:
         client.rotate_head(pitch, yaw)
         time.sleep(1.5)
 
+    logger.info("Centering head")
+    client.center_head()
+
     logger.info("Look-around example completed successfully")
 
 
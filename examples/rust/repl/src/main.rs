@@ -0,0 +1,38 @@
+//! Interactive command prompt example.
+//!
+//! Reads commands such as `move 0.3 0 0`, `mode walking`, `dance nezha` or
+//! `gripper open left` from stdin and sends them to the robot.
+//!
+//! Run with:
+//! `cargo run -p repl`
+
+use booster_sdk::{client::loco::BoosterClient, repl};
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tracing_subscriber::EnvFilter;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let env_filter = EnvFilter::new("off,booster_sdk=info");
+    tracing_subscriber::fmt().with_env_filter(env_filter).init();
+
+    let client = BoosterClient::new()?;
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+
+    println!("Enter commands, or `quit` to exit");
+    while let Some(line) = lines.next_line().await? {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if line == "quit" || line == "exit" {
+            break;
+        }
+
+        match repl::dispatch(&client, line).await {
+            Ok(message) => println!("{message}"),
+            Err(err) => println!("error: {err}"),
+        }
+    }
+
+    Ok(())
+}